@@ -4,6 +4,7 @@ use core::{
 		Borrow,
 		BorrowMut
 	},
+	array::IntoIter,
 	cmp::Ordering,
 	convert::TryFrom,
 	fmt::{
@@ -14,11 +15,16 @@ use core::{
 		self,
 		Hash,
 	},
+	mem::{
+		self,
+		MaybeUninit,
+	},
 	num::NonZeroUsize,
 	ops::{
 		Deref,
 		DerefMut,
 	},
+	ptr,
 	slice::{
 		Iter,
 		IterMut
@@ -26,6 +32,7 @@ use core::{
 };
 use alloc::vec::Vec;
 use super::{
+	iter::*,
 	//pointer_class::*,
 	slice::*,
 	vec::*,
@@ -50,6 +57,89 @@ impl<T, const N: NonZeroUsize> IdealArray<T, N> where [T; N.get()]: Sized {
 	pub fn get_mut<'a>(&'a mut self) -> &'a mut [T; N.get()] {
 		&mut self.0
 	}
+
+	#[inline]
+	pub fn into_ideal_iter(self) -> IdealIter<IntoIter<T, {N.get()}>> {
+		IdealIter(IntoIterator::into_iter(self.0))
+	}
+
+	#[inline]
+	pub fn map<U, F: FnMut(T) -> U>(self, f: F) -> IdealArray<U, N> where [U; N.get()]: Sized {
+		IdealArray(self.0.map(f))
+	}
+
+	/// Stops at the first error.
+	pub fn try_map<U, E, F: FnMut(T) -> Result<U, E>>(self, mut f: F) -> Result<IdealArray<U, N>, E> where [U; N.get()]: Sized {
+		let mut array = PartialArray::<U, {N.get()}>::new();
+		for value in IntoIterator::into_iter(self.0) {
+			array.push(f(value)?);
+		}
+		Ok(IdealArray(array.finish()))
+	}
+}
+
+impl<T, const N: NonZeroUsize> IdealArray<Option<T>, N> where [Option<T>; N.get()]: Sized {
+	pub fn transpose(self) -> Option<IdealArray<T, N>> where [T; N.get()]: Sized {
+		self.try_map(|option| option.ok_or(())).ok()
+	}
+}
+
+impl<T, E, const N: NonZeroUsize> IdealArray<Result<T, E>, N> where [Result<T, E>; N.get()]: Sized {
+	/// Stops at the first error.
+	pub fn transpose(self) -> Result<IdealArray<T, N>, E> where [T; N.get()]: Sized {
+		self.try_map(|result| result)
+	}
+
+	/// Like `transpose`, but collects every error.
+	pub fn transpose_all(self) -> Result<IdealArray<T, N>, IdealVec<E>> where [T; N.get()]: Sized {
+		let mut array = PartialArray::<T, {N.get()}>::new();
+		let mut errors = Vec::new();
+		for result in IntoIterator::into_iter(self.0) {
+			match result {
+				Ok(value) => array.push(value),
+				Err(error) => errors.push(error),
+			}
+		}
+		match IdealVec::new(errors) {
+			Ok(errors) => Err(errors),
+			Err(_) => Ok(IdealArray(array.finish())),
+		}
+	}
+}
+
+/// An array filled in place from the front, which drops the elements written so far if abandoned.
+struct PartialArray<T, const N: usize> {
+	array: [MaybeUninit<T>; N],
+	len: usize,
+}
+
+impl<T, const N: usize> PartialArray<T, N> {
+	fn new() -> Self {
+		Self {
+			array: unsafe {MaybeUninit::uninit().assume_init()},
+			len: 0,
+		}
+	}
+
+	/// Panics if the array is already full.
+	fn push(&mut self, value: T) {
+		self.array[self.len] = MaybeUninit::new(value);
+		self.len += 1;
+	}
+
+	/// Must only be called once every element has been written.
+	fn finish(self) -> [T; N] {
+		debug_assert_eq!(self.len, N);
+		let array = unsafe {ptr::read(&self.array as *const [MaybeUninit<T>; N] as *const [T; N])};
+		mem::forget(self);
+		array
+	}
+}
+
+impl<T, const N: usize> Drop for PartialArray<T, N> {
+	fn drop(&mut self) {
+		unsafe {ptr::drop_in_place(&mut self.array[..self.len] as *mut [MaybeUninit<T>] as *mut [T])}
+	}
 }
 
 impl<T, const N: NonZeroUsize> AsMut<[T]> for IdealArray<T, N> where [T; N.get()]: Sized {
@@ -151,6 +241,10 @@ impl<'a, T, const N: NonZeroUsize> IntoIterator for &'a mut IdealArray<T, N> whe
 	fn into_iter(self) -> Self::IntoIter {self.0.iter_mut()}
 }
 
+unsafe impl<'a, T, const N: NonZeroUsize> NonEmptyIterator for &'a IdealArray<T, N> where [T; N.get()]: Sized {}
+
+unsafe impl<'a, T, const N: NonZeroUsize> NonEmptyIterator for &'a mut IdealArray<T, N> where [T; N.get()]: Sized {}
+
 impl<'a, T: Ord, const N: NonZeroUsize> Ord for &'a IdealArray<T, N> where [T; N.get()]: Sized {
 	#[inline]
 	fn cmp(&self, other: &Self) -> Ordering {self.0.cmp(&other.0)}
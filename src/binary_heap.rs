@@ -0,0 +1,170 @@
+use core::{
+	fmt::{
+		self,
+		Debug,
+	},
+	num::NonZeroUsize,
+	ops::{
+		Deref,
+		DerefMut,
+	},
+};
+use alloc::collections::binary_heap::{
+	self,
+	BinaryHeap,
+	IntoIter,
+	Iter,
+};
+use super::{
+	error::*,
+	iter::*,
+	vec::*,
+};
+
+#[repr(transparent)]
+pub struct IdealBinaryHeap<T>(pub(crate) BinaryHeap<T>);
+
+impl<T> IdealBinaryHeap<T> {
+	pub unsafe fn new_unchecked(heap: BinaryHeap<T>) -> Self {
+		Self(heap)
+	}
+
+	pub fn new(heap: BinaryHeap<T>) -> Result<Self, EmptyError<BinaryHeap<T>>> {
+		if heap.len() != 0 {
+			Ok(Self(heap))
+		} else {
+			Err(EmptyError(heap))
+		}
+	}
+
+	#[track_caller]
+	pub fn expect_new(heap: BinaryHeap<T>) -> Self {
+		Self::new(heap).expect("expected a non-empty BinaryHeap")
+	}
+
+	pub fn get(self) -> BinaryHeap<T> {
+		self.0
+	}
+
+	pub fn get_ref<'a>(&'a self) -> &'a BinaryHeap<T> {
+		&self.0
+	}
+
+	pub unsafe fn get_mut<'a>(&'a mut self) -> &'a mut BinaryHeap<T> {
+		&mut self.0
+	}
+
+	pub fn len(&self) -> NonZeroUsize {
+		unsafe {NonZeroUsize::new_unchecked(self.0.len())}
+	}
+
+	#[inline]
+	pub fn peek(&self) -> &T {
+		unsafe {unwrap_unchecked(self.0.peek())}
+	}
+
+	#[inline]
+	pub fn iter<'a>(&'a self) -> IdealIter<Iter<'a, T>> {
+		IdealIter(self.0.iter())
+	}
+
+	#[inline]
+	pub fn into_ideal_iter(self) -> IdealIter<IntoIter<T>> {
+		IdealIter(self.0.into_iter())
+	}
+
+	/// Returns the underlying elements in arbitrary order.
+	pub fn into_ideal_vec(self) -> IdealVec<T> {
+		IdealVec(self.0.into_vec())
+	}
+}
+
+impl<T: Ord> IdealBinaryHeap<T> {
+	#[inline]
+	pub fn push(&mut self, item: T) {
+		self.0.push(item)
+	}
+
+	/// Removes the greatest element, unless it is the only one left.
+	pub fn pop_if_many(&mut self) -> Option<T> {
+		if self.0.len() > 1 {
+			self.0.pop()
+		} else {
+			None
+		}
+	}
+
+	/// Mutably borrows the greatest element, restoring the heap order when the borrow ends.
+	#[inline]
+	pub fn peek_mut<'a>(&'a mut self) -> PeekMut<'a, T> {
+		PeekMut(unsafe {unwrap_unchecked(self.0.peek_mut())})
+	}
+
+	pub fn into_sorted_ideal_vec(self) -> IdealVec<T> {
+		IdealVec(self.0.into_sorted_vec())
+	}
+}
+
+/// Unlike `binary_heap::PeekMut`, this cannot pop the element, as it may be the last one.
+pub struct PeekMut<'a, T: 'a + Ord>(binary_heap::PeekMut<'a, T>);
+
+impl<'a, T: Ord + Debug> Debug for PeekMut<'a, T> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {self.0.fmt(f)}
+}
+
+impl<'a, T: Ord> Deref for PeekMut<'a, T> {
+	type Target = T;
+	#[inline]
+	fn deref(&self) -> &T {&self.0}
+}
+
+impl<'a, T: Ord> DerefMut for PeekMut<'a, T> {
+	#[inline]
+	fn deref_mut(&mut self) -> &mut T {&mut self.0}
+}
+
+impl<T: Clone> Clone for IdealBinaryHeap<T> {
+	fn clone(&self) -> Self {
+		Self(self.0.clone())
+	}
+
+	fn clone_from(&mut self, other: &Self) {
+		self.0.clone_from(&other.0)
+	}
+}
+
+impl<T: Debug> Debug for IdealBinaryHeap<T> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {self.0.fmt(f)}
+}
+
+impl<'a, T: 'a + Ord + Copy> Extend<&'a T> for IdealBinaryHeap<T> {
+	#[inline]
+	fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {self.0.extend(iter)}
+}
+
+impl<T: Ord> Extend<T> for IdealBinaryHeap<T> {
+	#[inline]
+	fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {self.0.extend(iter)}
+}
+
+impl<T: Ord> From<IdealVec<T>> for IdealBinaryHeap<T> {
+	fn from(v: IdealVec<T>) -> Self {Self(BinaryHeap::from(v.0))}
+}
+
+impl<T> IntoIterator for IdealBinaryHeap<T> {
+	type Item = T;
+	type IntoIter = IntoIter<T>;
+	#[inline]
+	fn into_iter(self) -> Self::IntoIter {
+		self.0.into_iter()
+	}
+}
+
+impl<'a, T> IntoIterator for &'a IdealBinaryHeap<T> {
+	type Item = &'a T;
+	type IntoIter = Iter<'a, T>;
+	#[inline]
+	fn into_iter(self) -> Self::IntoIter {
+		self.0.iter()
+	}
+}
\ No newline at end of file
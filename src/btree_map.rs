@@ -0,0 +1,292 @@
+use core::{
+	borrow::Borrow,
+	cmp::Ordering,
+	fmt::{
+		self,
+		Debug,
+	},
+	hash::{
+		self,
+		Hash,
+	},
+	iter::FromIterator,
+	num::NonZeroUsize,
+	ops::{
+		Index,
+		RangeBounds,
+	},
+};
+use alloc::{
+	collections::btree_map::{
+		BTreeMap,
+		IntoIter,
+		IntoKeys,
+		IntoValues,
+		Iter,
+		IterMut,
+		Keys,
+		Range,
+		RangeMut,
+		Values,
+		ValuesMut,
+	},
+	vec::Vec,
+};
+use super::{
+	error::*,
+	iter::*,
+	vec::*,
+};
+
+#[repr(transparent)]
+pub struct IdealBTreeMap<K, V>(pub(crate) BTreeMap<K, V>);
+
+impl<K, V> IdealBTreeMap<K, V> {
+	pub unsafe fn new_unchecked(map: BTreeMap<K, V>) -> Self {
+		Self(map)
+	}
+
+	pub fn new(map: BTreeMap<K, V>) -> Result<Self, EmptyError<BTreeMap<K, V>>> {
+		if map.len() != 0 {
+			Ok(Self(map))
+		} else {
+			Err(EmptyError(map))
+		}
+	}
+
+	#[track_caller]
+	pub fn expect_new(map: BTreeMap<K, V>) -> Self {
+		Self::new(map).expect("expected a non-empty BTreeMap")
+	}
+
+	pub fn get(self) -> BTreeMap<K, V> {
+		self.0
+	}
+
+	pub fn get_ref<'a>(&'a self) -> &'a BTreeMap<K, V> {
+		&self.0
+	}
+
+	pub unsafe fn get_mut<'a>(&'a mut self) -> &'a mut BTreeMap<K, V> {
+		&mut self.0
+	}
+
+	pub fn len(&self) -> NonZeroUsize {
+		unsafe {NonZeroUsize::new_unchecked(self.0.len())}
+	}
+
+	#[inline]
+	pub fn iter<'a>(&'a self) -> IdealIter<Iter<'a, K, V>> {
+		IdealIter(self.0.iter())
+	}
+
+	#[inline]
+	pub fn iter_mut<'a>(&'a mut self) -> IdealIter<IterMut<'a, K, V>> {
+		IdealIter(self.0.iter_mut())
+	}
+
+	#[inline]
+	pub fn keys<'a>(&'a self) -> IdealIter<Keys<'a, K, V>> {
+		IdealIter(self.0.keys())
+	}
+
+	#[inline]
+	pub fn values<'a>(&'a self) -> IdealIter<Values<'a, K, V>> {
+		IdealIter(self.0.values())
+	}
+
+	#[inline]
+	pub fn values_mut<'a>(&'a mut self) -> IdealIter<ValuesMut<'a, K, V>> {
+		IdealIter(self.0.values_mut())
+	}
+
+	#[inline]
+	pub fn into_ideal_iter(self) -> IdealIter<IntoIter<K, V>> {
+		IdealIter(self.0.into_iter())
+	}
+
+	#[inline]
+	pub fn into_keys(self) -> IdealIter<IntoKeys<K, V>> {
+		IdealIter(self.0.into_keys())
+	}
+
+	#[inline]
+	pub fn into_values(self) -> IdealIter<IntoValues<K, V>> {
+		IdealIter(self.0.into_values())
+	}
+
+	pub fn into_ideal_vec(self) -> IdealVec<(K, V)> {
+		IdealVec(Vec::from_iter(self.0))
+	}
+}
+
+impl<K: Ord, V> IdealBTreeMap<K, V> {
+	pub fn from_pair(key: K, value: V) -> Self {
+		let mut map = BTreeMap::new();
+		map.insert(key, value);
+		Self(map)
+	}
+
+	#[inline]
+	pub fn first_key_value(&self) -> (&K, &V) {
+		unsafe {unwrap_unchecked(self.0.first_key_value())}
+	}
+
+	#[inline]
+	pub fn last_key_value(&self) -> (&K, &V) {
+		unsafe {unwrap_unchecked(self.0.last_key_value())}
+	}
+
+	#[inline]
+	pub fn get_value<Q: ?Sized + Ord>(&self, key: &Q) -> Option<&V> where K: Borrow<Q> {
+		self.0.get(key)
+	}
+
+	#[inline]
+	pub fn get_value_mut<Q: ?Sized + Ord>(&mut self, key: &Q) -> Option<&mut V> where K: Borrow<Q> {
+		self.0.get_mut(key)
+	}
+
+	#[inline]
+	pub fn contains_key<Q: ?Sized + Ord>(&self, key: &Q) -> bool where K: Borrow<Q> {
+		self.0.contains_key(key)
+	}
+
+	#[inline]
+	pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+		self.0.insert(key, value)
+	}
+
+	/// Removes the entry for `key`, returning `Ok(None)` if it is absent.
+	///
+	/// Fails, leaving the map untouched, if the entry is the only one left.
+	pub fn remove_if_many<Q: ?Sized + Ord>(&mut self, key: &Q) -> Result<Option<V>, LastEntryError> where K: Borrow<Q> {
+		self.remove_entry_if_many(key).map(|entry| entry.map(|(_, value)| value))
+	}
+
+	/// Removes the entry for `key`, returning `Ok(None)` if it is absent.
+	///
+	/// Fails, leaving the map untouched, if the entry is the only one left.
+	pub fn remove_entry_if_many<Q: ?Sized + Ord>(&mut self, key: &Q) -> Result<Option<(K, V)>, LastEntryError> where K: Borrow<Q> {
+		if self.0.len() > 1 {
+			Ok(self.0.remove_entry(key))
+		} else if self.0.contains_key(key) {
+			Err(LastEntryError)
+		} else {
+			Ok(None)
+		}
+	}
+
+	/// Removes the first entry, unless it is the only one left.
+	pub fn pop_first_if_many(&mut self) -> Option<(K, V)> {
+		if self.0.len() > 1 {
+			self.0.pop_first()
+		} else {
+			None
+		}
+	}
+
+	/// Removes the last entry, unless it is the only one left.
+	pub fn pop_last_if_many(&mut self) -> Option<(K, V)> {
+		if self.0.len() > 1 {
+			self.0.pop_last()
+		} else {
+			None
+		}
+	}
+
+	#[inline]
+	pub fn range<'a, Q: ?Sized + Ord, R: RangeBounds<Q>>(&'a self, range: R) -> Range<'a, K, V> where K: Borrow<Q> {
+		self.0.range(range)
+	}
+
+	#[inline]
+	pub fn range_mut<'a, Q: ?Sized + Ord, R: RangeBounds<Q>>(&'a mut self, range: R) -> RangeMut<'a, K, V> where K: Borrow<Q> {
+		self.0.range_mut(range)
+	}
+}
+
+impl<K: Clone, V: Clone> Clone for IdealBTreeMap<K, V> {
+	fn clone(&self) -> Self {
+		Self(self.0.clone())
+	}
+}
+
+impl<K: Debug, V: Debug> Debug for IdealBTreeMap<K, V> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {self.0.fmt(f)}
+}
+
+impl<K: Eq, V: Eq> Eq for IdealBTreeMap<K, V> {}
+
+impl<'a, K: Ord + Copy, V: Copy> Extend<(&'a K, &'a V)> for IdealBTreeMap<K, V> {
+	#[inline]
+	fn extend<I: IntoIterator<Item = (&'a K, &'a V)>>(&mut self, iter: I) {self.0.extend(iter)}
+}
+
+impl<K: Ord, V> Extend<(K, V)> for IdealBTreeMap<K, V> {
+	#[inline]
+	fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {self.0.extend(iter)}
+}
+
+/// Later pairs overwrite earlier ones with the same key, as with `BTreeMap::from_iter`.
+impl<K: Ord, V> From<IdealVec<(K, V)>> for IdealBTreeMap<K, V> {
+	fn from(v: IdealVec<(K, V)>) -> Self {Self(BTreeMap::from_iter(v.0))}
+}
+
+impl<K: Hash, V: Hash> Hash for IdealBTreeMap<K, V> {
+	#[inline]
+	fn hash<H: hash::Hasher>(&self, state: &mut H) {self.0.hash(state)}
+}
+
+impl<K: Ord + Borrow<Q>, Q: ?Sized + Ord, V> Index<&Q> for IdealBTreeMap<K, V> {
+	type Output = V;
+	#[inline]
+	fn index(&self, key: &Q) -> &V {self.0.index(key)}
+}
+
+impl<K, V> IntoIterator for IdealBTreeMap<K, V> {
+	type Item = (K, V);
+	type IntoIter = IntoIter<K, V>;
+	#[inline]
+	fn into_iter(self) -> Self::IntoIter {
+		self.0.into_iter()
+	}
+}
+
+impl<'a, K, V> IntoIterator for &'a IdealBTreeMap<K, V> {
+	type Item = (&'a K, &'a V);
+	type IntoIter = Iter<'a, K, V>;
+	#[inline]
+	fn into_iter(self) -> Self::IntoIter {
+		self.0.iter()
+	}
+}
+
+impl<'a, K, V> IntoIterator for &'a mut IdealBTreeMap<K, V> {
+	type Item = (&'a K, &'a mut V);
+	type IntoIter = IterMut<'a, K, V>;
+	#[inline]
+	fn into_iter(self) -> Self::IntoIter {
+		self.0.iter_mut()
+	}
+}
+
+impl<K: Ord, V: Ord> Ord for IdealBTreeMap<K, V> {
+	#[inline]
+	fn cmp(&self, other: &Self) -> Ordering {self.0.cmp(&other.0)}
+}
+
+impl<K: PartialEq, V: PartialEq> PartialEq<BTreeMap<K, V>> for IdealBTreeMap<K, V> {
+	#[inline]
+	fn eq(&self, other: &BTreeMap<K, V>) -> bool {self.0 == *other}
+}
+
+impl<K: PartialEq, V: PartialEq> PartialEq<Self> for IdealBTreeMap<K, V> {
+	#[inline]
+	fn eq(&self, other: &Self) -> bool {self.0 == other.0}
+}
+
+impl<K: PartialOrd, V: PartialOrd> PartialOrd<Self> for IdealBTreeMap<K, V> {
+	#[inline]
+	fn partial_cmp(&self, other: &Self) -> Option<Ordering> {self.0.partial_cmp(&other.0)}
+}
\ No newline at end of file
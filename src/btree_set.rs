@@ -0,0 +1,245 @@
+use core::{
+	borrow::Borrow,
+	cmp::Ordering,
+	fmt::{
+		self,
+		Debug,
+	},
+	hash::{
+		self,
+		Hash,
+	},
+	iter::FromIterator,
+	num::NonZeroUsize,
+	ops::RangeBounds,
+};
+use alloc::{
+	collections::btree_set::{
+		BTreeSet,
+		IntoIter,
+		Iter,
+		Range,
+	},
+	vec::Vec,
+};
+use super::{
+	error::*,
+	iter::*,
+	vec::*,
+};
+
+#[repr(transparent)]
+pub struct IdealBTreeSet<T>(pub(crate) BTreeSet<T>);
+
+impl<T> IdealBTreeSet<T> {
+	pub unsafe fn new_unchecked(set: BTreeSet<T>) -> Self {
+		Self(set)
+	}
+
+	pub fn new(set: BTreeSet<T>) -> Result<Self, EmptyError<BTreeSet<T>>> {
+		if set.len() != 0 {
+			Ok(Self(set))
+		} else {
+			Err(EmptyError(set))
+		}
+	}
+
+	#[track_caller]
+	pub fn expect_new(set: BTreeSet<T>) -> Self {
+		Self::new(set).expect("expected a non-empty BTreeSet")
+	}
+
+	pub fn get(self) -> BTreeSet<T> {
+		self.0
+	}
+
+	pub fn get_ref<'a>(&'a self) -> &'a BTreeSet<T> {
+		&self.0
+	}
+
+	pub unsafe fn get_mut<'a>(&'a mut self) -> &'a mut BTreeSet<T> {
+		&mut self.0
+	}
+
+	pub fn len(&self) -> NonZeroUsize {
+		unsafe {NonZeroUsize::new_unchecked(self.0.len())}
+	}
+
+	#[inline]
+	pub fn iter<'a>(&'a self) -> IdealIter<Iter<'a, T>> {
+		IdealIter(self.0.iter())
+	}
+
+	#[inline]
+	pub fn into_ideal_iter(self) -> IdealIter<IntoIter<T>> {
+		IdealIter(self.0.into_iter())
+	}
+
+	pub fn into_sorted_ideal_vec(self) -> IdealVec<T> {
+		IdealVec(Vec::from_iter(self.0))
+	}
+}
+
+impl<T: Ord> IdealBTreeSet<T> {
+	pub fn from_value(value: T) -> Self {
+		let mut set = BTreeSet::new();
+		set.insert(value);
+		Self(set)
+	}
+
+	#[inline]
+	pub fn first(&self) -> &T {
+		unsafe {unwrap_unchecked(self.0.first())}
+	}
+
+	#[inline]
+	pub fn last(&self) -> &T {
+		unsafe {unwrap_unchecked(self.0.last())}
+	}
+
+	#[inline]
+	pub fn contains<Q: ?Sized + Ord>(&self, value: &Q) -> bool where T: Borrow<Q> {
+		self.0.contains(value)
+	}
+
+	#[inline]
+	pub fn insert(&mut self, value: T) -> bool {
+		self.0.insert(value)
+	}
+
+	/// Removes `value`, returning `Ok(false)` if it is absent.
+	///
+	/// Fails, leaving the set untouched, if `value` is the only one left.
+	pub fn remove_if_many<Q: ?Sized + Ord>(&mut self, value: &Q) -> Result<bool, LastEntryError> where T: Borrow<Q> {
+		if self.0.len() > 1 {
+			Ok(self.0.remove(value))
+		} else if self.0.contains(value) {
+			Err(LastEntryError)
+		} else {
+			Ok(false)
+		}
+	}
+
+	/// Removes the first value, unless it is the only one left.
+	pub fn pop_first_if_many(&mut self) -> Option<T> {
+		if self.0.len() > 1 {
+			self.0.pop_first()
+		} else {
+			None
+		}
+	}
+
+	/// Removes the last value, unless it is the only one left.
+	pub fn pop_last_if_many(&mut self) -> Option<T> {
+		if self.0.len() > 1 {
+			self.0.pop_last()
+		} else {
+			None
+		}
+	}
+
+	#[inline]
+	pub fn range<'a, Q: ?Sized + Ord, R: RangeBounds<Q>>(&'a self, range: R) -> Range<'a, T> where T: Borrow<Q> {
+		self.0.range(range)
+	}
+
+	pub fn union(&self, other: &Self) -> Self where T: Clone {
+		Self(self.0.union(&other.0).cloned().collect())
+	}
+
+	pub fn intersection(&self, other: &Self) -> Option<Self> where T: Clone {
+		Self::new(self.0.intersection(&other.0).cloned().collect()).ok()
+	}
+
+	pub fn difference(&self, other: &Self) -> Option<Self> where T: Clone {
+		Self::new(self.0.difference(&other.0).cloned().collect()).ok()
+	}
+
+	pub fn symmetric_difference(&self, other: &Self) -> Option<Self> where T: Clone {
+		Self::new(self.0.symmetric_difference(&other.0).cloned().collect()).ok()
+	}
+
+	#[inline]
+	pub fn is_disjoint(&self, other: &Self) -> bool {
+		self.0.is_disjoint(&other.0)
+	}
+
+	#[inline]
+	pub fn is_subset(&self, other: &Self) -> bool {
+		self.0.is_subset(&other.0)
+	}
+
+	#[inline]
+	pub fn is_superset(&self, other: &Self) -> bool {
+		self.0.is_superset(&other.0)
+	}
+}
+
+impl<T: Clone> Clone for IdealBTreeSet<T> {
+	fn clone(&self) -> Self {
+		Self(self.0.clone())
+	}
+}
+
+impl<T: Debug> Debug for IdealBTreeSet<T> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {self.0.fmt(f)}
+}
+
+impl<T: Eq> Eq for IdealBTreeSet<T> {}
+
+impl<'a, T: 'a + Ord + Copy> Extend<&'a T> for IdealBTreeSet<T> {
+	#[inline]
+	fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {self.0.extend(iter)}
+}
+
+impl<T: Ord> Extend<T> for IdealBTreeSet<T> {
+	#[inline]
+	fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {self.0.extend(iter)}
+}
+
+impl<T: Ord> From<IdealVec<T>> for IdealBTreeSet<T> {
+	fn from(v: IdealVec<T>) -> Self {Self(BTreeSet::from_iter(v.0))}
+}
+
+impl<T: Hash> Hash for IdealBTreeSet<T> {
+	#[inline]
+	fn hash<H: hash::Hasher>(&self, state: &mut H) {self.0.hash(state)}
+}
+
+impl<T> IntoIterator for IdealBTreeSet<T> {
+	type Item = T;
+	type IntoIter = IntoIter<T>;
+	#[inline]
+	fn into_iter(self) -> Self::IntoIter {
+		self.0.into_iter()
+	}
+}
+
+impl<'a, T> IntoIterator for &'a IdealBTreeSet<T> {
+	type Item = &'a T;
+	type IntoIter = Iter<'a, T>;
+	#[inline]
+	fn into_iter(self) -> Self::IntoIter {
+		self.0.iter()
+	}
+}
+
+impl<T: Ord> Ord for IdealBTreeSet<T> {
+	#[inline]
+	fn cmp(&self, other: &Self) -> Ordering {self.0.cmp(&other.0)}
+}
+
+impl<T: PartialEq> PartialEq<BTreeSet<T>> for IdealBTreeSet<T> {
+	#[inline]
+	fn eq(&self, other: &BTreeSet<T>) -> bool {self.0 == *other}
+}
+
+impl<T: PartialEq> PartialEq<Self> for IdealBTreeSet<T> {
+	#[inline]
+	fn eq(&self, other: &Self) -> bool {self.0 == other.0}
+}
+
+impl<T: PartialOrd> PartialOrd<Self> for IdealBTreeSet<T> {
+	#[inline]
+	fn partial_cmp(&self, other: &Self) -> Option<Ordering> {self.0.partial_cmp(&other.0)}
+}
\ No newline at end of file
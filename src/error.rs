@@ -0,0 +1,55 @@
+use core::fmt::{
+	self,
+	Debug,
+	Display,
+};
+
+/// The error returned when constructing a non-empty wrapper from an empty container, which it hands back.
+pub struct EmptyError<C>(pub(crate) C);
+
+impl<C> EmptyError<C> {
+	#[inline]
+	pub fn get(self) -> C {
+		self.0
+	}
+
+	#[inline]
+	pub fn get_ref<'a>(&'a self) -> &'a C {
+		&self.0
+	}
+}
+
+impl<C: Clone> Clone for EmptyError<C> {
+	fn clone(&self) -> Self {
+		Self(self.0.clone())
+	}
+}
+
+impl<C> Debug for EmptyError<C> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {f.debug_struct("EmptyError").finish_non_exhaustive()}
+}
+
+impl<C> Display for EmptyError<C> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {f.write_str("expected a non-empty container")}
+}
+
+#[cfg(feature = "std")]
+impl<C> std::error::Error for EmptyError<C> {}
+
+impl<C: PartialEq> PartialEq for EmptyError<C> {
+	#[inline]
+	fn eq(&self, other: &Self) -> bool {self.0 == other.0}
+}
+
+impl<C: Eq> Eq for EmptyError<C> {}
+
+/// The error returned when removing an entry would leave a non-empty collection empty.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct LastEntryError;
+
+impl Display for LastEntryError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {f.write_str("cannot remove the only entry of a non-empty collection")}
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for LastEntryError {}
\ No newline at end of file
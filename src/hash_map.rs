@@ -0,0 +1,264 @@
+use core::{
+	borrow::Borrow,
+	fmt::{
+		self,
+		Debug,
+	},
+	hash::{
+		BuildHasher,
+		Hash,
+	},
+	iter::FromIterator,
+	num::NonZeroUsize,
+	ops::Index,
+};
+use alloc::vec::Vec;
+#[cfg(feature = "hashbrown")]
+use hashbrown::hash_map::{
+	DefaultHashBuilder,
+	HashMap,
+	IntoIter,
+	Iter,
+	IterMut,
+	Keys,
+	Values,
+	ValuesMut,
+};
+#[cfg(not(feature = "hashbrown"))]
+use std::collections::hash_map::{
+	HashMap,
+	IntoIter,
+	Iter,
+	IterMut,
+	Keys,
+	RandomState as DefaultHashBuilder,
+	Values,
+	ValuesMut,
+};
+use super::{
+	error::*,
+	iter::*,
+	vec::*,
+};
+
+#[repr(transparent)]
+pub struct IdealHashMap<K, V, S = DefaultHashBuilder>(pub(crate) HashMap<K, V, S>);
+
+impl<K, V, S> IdealHashMap<K, V, S> {
+	pub unsafe fn new_unchecked(map: HashMap<K, V, S>) -> Self {
+		Self(map)
+	}
+
+	pub fn new(map: HashMap<K, V, S>) -> Result<Self, EmptyError<HashMap<K, V, S>>> {
+		if map.len() != 0 {
+			Ok(Self(map))
+		} else {
+			Err(EmptyError(map))
+		}
+	}
+
+	#[track_caller]
+	pub fn expect_new(map: HashMap<K, V, S>) -> Self {
+		Self::new(map).expect("expected a non-empty HashMap")
+	}
+
+	pub fn get(self) -> HashMap<K, V, S> {
+		self.0
+	}
+
+	pub fn get_ref<'a>(&'a self) -> &'a HashMap<K, V, S> {
+		&self.0
+	}
+
+	pub unsafe fn get_mut<'a>(&'a mut self) -> &'a mut HashMap<K, V, S> {
+		&mut self.0
+	}
+
+	pub fn len(&self) -> NonZeroUsize {
+		unsafe {NonZeroUsize::new_unchecked(self.0.len())}
+	}
+
+	/// Returns an arbitrary entry.
+	#[inline]
+	pub fn any(&self) -> (&K, &V) {
+		unsafe {unwrap_unchecked(self.0.iter().next())}
+	}
+
+	#[inline]
+	pub fn iter<'a>(&'a self) -> IdealIter<Iter<'a, K, V>> {
+		IdealIter(self.0.iter())
+	}
+
+	#[inline]
+	pub fn iter_mut<'a>(&'a mut self) -> IdealIter<IterMut<'a, K, V>> {
+		IdealIter(self.0.iter_mut())
+	}
+
+	#[inline]
+	pub fn keys<'a>(&'a self) -> IdealIter<Keys<'a, K, V>> {
+		IdealIter(self.0.keys())
+	}
+
+	#[inline]
+	pub fn values<'a>(&'a self) -> IdealIter<Values<'a, K, V>> {
+		IdealIter(self.0.values())
+	}
+
+	#[inline]
+	pub fn values_mut<'a>(&'a mut self) -> IdealIter<ValuesMut<'a, K, V>> {
+		IdealIter(self.0.values_mut())
+	}
+
+	#[inline]
+	pub fn into_ideal_iter(self) -> IdealIter<IntoIter<K, V>> {
+		IdealIter(self.0.into_iter())
+	}
+
+	/// Returns the entries in arbitrary order.
+	pub fn into_ideal_vec(self) -> IdealVec<(K, V)> {
+		IdealVec(Vec::from_iter(self.0))
+	}
+}
+
+impl<K: Eq + Hash, V, S: BuildHasher> IdealHashMap<K, V, S> {
+	#[inline]
+	pub fn get_value<Q: ?Sized + Eq + Hash>(&self, key: &Q) -> Option<&V> where K: Borrow<Q> {
+		self.0.get(key)
+	}
+
+	#[inline]
+	pub fn get_value_mut<Q: ?Sized + Eq + Hash>(&mut self, key: &Q) -> Option<&mut V> where K: Borrow<Q> {
+		self.0.get_mut(key)
+	}
+
+	#[inline]
+	pub fn contains_key<Q: ?Sized + Eq + Hash>(&self, key: &Q) -> bool where K: Borrow<Q> {
+		self.0.contains_key(key)
+	}
+
+	#[inline]
+	pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+		self.0.insert(key, value)
+	}
+
+	/// Removes the entry for `key`, returning `Ok(None)` if it is absent.
+	///
+	/// Fails, leaving the map untouched, if the entry is the only one left.
+	pub fn remove_if_many<Q: ?Sized + Eq + Hash>(&mut self, key: &Q) -> Result<Option<V>, LastEntryError> where K: Borrow<Q> {
+		self.remove_entry_if_many(key).map(|entry| entry.map(|(_, value)| value))
+	}
+
+	/// Removes the entry for `key`, returning `Ok(None)` if it is absent.
+	///
+	/// Fails, leaving the map untouched, if the entry is the only one left.
+	pub fn remove_entry_if_many<Q: ?Sized + Eq + Hash>(&mut self, key: &Q) -> Result<Option<(K, V)>, LastEntryError> where K: Borrow<Q> {
+		if self.0.len() > 1 {
+			Ok(self.0.remove_entry(key))
+		} else if self.0.contains_key(key) {
+			Err(LastEntryError)
+		} else {
+			Ok(None)
+		}
+	}
+
+	/// Keeps only the entries for which `f` returns `true`, calling it exactly once per entry.
+	///
+	/// If `f` rejects every entry, the map is left untouched and `false` is returned.
+	pub fn retain<F: FnMut(&K, &mut V) -> bool>(&mut self, mut f: F) -> bool {
+		let mut restore = RestoreIfEmpty {map: &mut self.0, removed: Vec::new()};
+		let mut panicked = false;
+		restore.removed.extend(restore.map.drain_filter(|key, value| {
+			if panicked {
+				return false;
+			}
+			panicked = true;
+			let keep = f(key, value);
+			panicked = false;
+			!keep
+		}));
+		!restore.map.is_empty()
+	}
+}
+
+/// Entries drained from a map, which are put back on drop if the map was left empty.
+struct RestoreIfEmpty<'a, K: Eq + Hash, V, S: BuildHasher> {
+	map: &'a mut HashMap<K, V, S>,
+	removed: Vec<(K, V)>,
+}
+
+impl<'a, K: Eq + Hash, V, S: BuildHasher> Drop for RestoreIfEmpty<'a, K, V, S> {
+	fn drop(&mut self) {
+		if self.map.is_empty() {
+			self.map.extend(self.removed.drain(..));
+		}
+	}
+}
+
+impl<K: Clone, V: Clone, S: Clone> Clone for IdealHashMap<K, V, S> {
+	fn clone(&self) -> Self {
+		Self(self.0.clone())
+	}
+}
+
+impl<K: Debug, V: Debug, S> Debug for IdealHashMap<K, V, S> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {self.0.fmt(f)}
+}
+
+impl<K: Eq + Hash, V: Eq, S: BuildHasher> Eq for IdealHashMap<K, V, S> {}
+
+impl<'a, K: Eq + Hash + Copy, V: Copy, S: BuildHasher> Extend<(&'a K, &'a V)> for IdealHashMap<K, V, S> {
+	#[inline]
+	fn extend<I: IntoIterator<Item = (&'a K, &'a V)>>(&mut self, iter: I) {self.0.extend(iter)}
+}
+
+impl<K: Eq + Hash, V, S: BuildHasher> Extend<(K, V)> for IdealHashMap<K, V, S> {
+	#[inline]
+	fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {self.0.extend(iter)}
+}
+
+impl<K: Eq + Hash, V, S: BuildHasher + Default> From<IdealVec<(K, V)>> for IdealHashMap<K, V, S> {
+	fn from(v: IdealVec<(K, V)>) -> Self {Self(HashMap::from_iter(v.0))}
+}
+
+impl<K: Eq + Hash + Borrow<Q>, Q: ?Sized + Eq + Hash, V, S: BuildHasher> Index<&Q> for IdealHashMap<K, V, S> {
+	type Output = V;
+	#[inline]
+	fn index(&self, key: &Q) -> &V {self.0.index(key)}
+}
+
+impl<K, V, S> IntoIterator for IdealHashMap<K, V, S> {
+	type Item = (K, V);
+	type IntoIter = IntoIter<K, V>;
+	#[inline]
+	fn into_iter(self) -> Self::IntoIter {
+		self.0.into_iter()
+	}
+}
+
+impl<'a, K, V, S> IntoIterator for &'a IdealHashMap<K, V, S> {
+	type Item = (&'a K, &'a V);
+	type IntoIter = Iter<'a, K, V>;
+	#[inline]
+	fn into_iter(self) -> Self::IntoIter {
+		self.0.iter()
+	}
+}
+
+impl<'a, K, V, S> IntoIterator for &'a mut IdealHashMap<K, V, S> {
+	type Item = (&'a K, &'a mut V);
+	type IntoIter = IterMut<'a, K, V>;
+	#[inline]
+	fn into_iter(self) -> Self::IntoIter {
+		self.0.iter_mut()
+	}
+}
+
+impl<K: Eq + Hash, V: PartialEq, S: BuildHasher> PartialEq<HashMap<K, V, S>> for IdealHashMap<K, V, S> {
+	#[inline]
+	fn eq(&self, other: &HashMap<K, V, S>) -> bool {self.0 == *other}
+}
+
+impl<K: Eq + Hash, V: PartialEq, S: BuildHasher> PartialEq<Self> for IdealHashMap<K, V, S> {
+	#[inline]
+	fn eq(&self, other: &Self) -> bool {self.0 == other.0}
+}
\ No newline at end of file
@@ -0,0 +1,240 @@
+use core::{
+	borrow::Borrow,
+	fmt::{
+		self,
+		Debug,
+	},
+	hash::{
+		BuildHasher,
+		Hash,
+	},
+	iter::FromIterator,
+	num::NonZeroUsize,
+};
+use alloc::vec::Vec;
+#[cfg(feature = "hashbrown")]
+use hashbrown::{
+	hash_map::DefaultHashBuilder,
+	hash_set::{
+		HashSet,
+		IntoIter,
+		Iter,
+	},
+};
+#[cfg(not(feature = "hashbrown"))]
+use std::collections::{
+	hash_map::RandomState as DefaultHashBuilder,
+	hash_set::{
+		HashSet,
+		IntoIter,
+		Iter,
+	},
+};
+use super::{
+	error::*,
+	iter::*,
+	vec::*,
+};
+
+#[repr(transparent)]
+pub struct IdealHashSet<T, S = DefaultHashBuilder>(pub(crate) HashSet<T, S>);
+
+impl<T, S> IdealHashSet<T, S> {
+	pub unsafe fn new_unchecked(set: HashSet<T, S>) -> Self {
+		Self(set)
+	}
+
+	pub fn new(set: HashSet<T, S>) -> Result<Self, EmptyError<HashSet<T, S>>> {
+		if set.len() != 0 {
+			Ok(Self(set))
+		} else {
+			Err(EmptyError(set))
+		}
+	}
+
+	#[track_caller]
+	pub fn expect_new(set: HashSet<T, S>) -> Self {
+		Self::new(set).expect("expected a non-empty HashSet")
+	}
+
+	pub fn get(self) -> HashSet<T, S> {
+		self.0
+	}
+
+	pub fn get_ref<'a>(&'a self) -> &'a HashSet<T, S> {
+		&self.0
+	}
+
+	pub unsafe fn get_mut<'a>(&'a mut self) -> &'a mut HashSet<T, S> {
+		&mut self.0
+	}
+
+	pub fn len(&self) -> NonZeroUsize {
+		unsafe {NonZeroUsize::new_unchecked(self.0.len())}
+	}
+
+	/// Returns an arbitrary value.
+	#[inline]
+	pub fn any(&self) -> &T {
+		unsafe {unwrap_unchecked(self.0.iter().next())}
+	}
+
+	#[inline]
+	pub fn iter<'a>(&'a self) -> IdealIter<Iter<'a, T>> {
+		IdealIter(self.0.iter())
+	}
+
+	#[inline]
+	pub fn into_ideal_iter(self) -> IdealIter<IntoIter<T>> {
+		IdealIter(self.0.into_iter())
+	}
+
+	/// Returns the values in arbitrary order.
+	pub fn into_ideal_vec(self) -> IdealVec<T> {
+		IdealVec(Vec::from_iter(self.0))
+	}
+}
+
+impl<T: Eq + Hash, S: BuildHasher> IdealHashSet<T, S> {
+	#[inline]
+	pub fn contains<Q: ?Sized + Eq + Hash>(&self, value: &Q) -> bool where T: Borrow<Q> {
+		self.0.contains(value)
+	}
+
+	#[inline]
+	pub fn insert(&mut self, value: T) -> bool {
+		self.0.insert(value)
+	}
+
+	/// Removes `value`, returning `Ok(false)` if it is absent.
+	///
+	/// Fails, leaving the set untouched, if `value` is the only one left.
+	pub fn remove_if_many<Q: ?Sized + Eq + Hash>(&mut self, value: &Q) -> Result<bool, LastEntryError> where T: Borrow<Q> {
+		if self.0.len() > 1 {
+			Ok(self.0.remove(value))
+		} else if self.0.contains(value) {
+			Err(LastEntryError)
+		} else {
+			Ok(false)
+		}
+	}
+
+	/// Keeps only the values for which `f` returns `true`, calling it exactly once per value.
+	///
+	/// If `f` rejects every value, the set is left untouched and `false` is returned.
+	pub fn retain<F: FnMut(&T) -> bool>(&mut self, mut f: F) -> bool {
+		let mut restore = RestoreIfEmpty {set: &mut self.0, removed: Vec::new()};
+		let mut panicked = false;
+		restore.removed.extend(restore.set.drain_filter(|value| {
+			if panicked {
+				return false;
+			}
+			panicked = true;
+			let keep = f(value);
+			panicked = false;
+			!keep
+		}));
+		!restore.set.is_empty()
+	}
+
+	pub fn union(&self, other: &Self) -> Self where T: Clone, S: Clone {
+		let mut set = self.0.clone();
+		set.extend(other.0.iter().cloned());
+		Self(set)
+	}
+
+	pub fn intersection(&self, other: &Self) -> Option<Self> where T: Clone, S: Clone {
+		let mut set = self.0.clone();
+		set.retain(|value| other.0.contains(value));
+		Self::new(set).ok()
+	}
+
+	pub fn difference(&self, other: &Self) -> Option<Self> where T: Clone, S: Clone {
+		let mut set = self.0.clone();
+		set.retain(|value| !other.0.contains(value));
+		Self::new(set).ok()
+	}
+
+	#[inline]
+	pub fn is_disjoint(&self, other: &Self) -> bool {
+		self.0.is_disjoint(&other.0)
+	}
+
+	#[inline]
+	pub fn is_subset(&self, other: &Self) -> bool {
+		self.0.is_subset(&other.0)
+	}
+
+	#[inline]
+	pub fn is_superset(&self, other: &Self) -> bool {
+		self.0.is_superset(&other.0)
+	}
+}
+
+/// Values drained from a set, which are put back on drop if the set was left empty.
+struct RestoreIfEmpty<'a, T: Eq + Hash, S: BuildHasher> {
+	set: &'a mut HashSet<T, S>,
+	removed: Vec<T>,
+}
+
+impl<'a, T: Eq + Hash, S: BuildHasher> Drop for RestoreIfEmpty<'a, T, S> {
+	fn drop(&mut self) {
+		if self.set.is_empty() {
+			self.set.extend(self.removed.drain(..));
+		}
+	}
+}
+
+impl<T: Clone, S: Clone> Clone for IdealHashSet<T, S> {
+	fn clone(&self) -> Self {
+		Self(self.0.clone())
+	}
+}
+
+impl<T: Debug, S> Debug for IdealHashSet<T, S> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {f.debug_set().entries(self.0.iter()).finish()}
+}
+
+impl<T: Eq + Hash, S: BuildHasher> Eq for IdealHashSet<T, S> {}
+
+impl<'a, T: 'a + Eq + Hash + Copy, S: BuildHasher> Extend<&'a T> for IdealHashSet<T, S> {
+	#[inline]
+	fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {self.0.extend(iter)}
+}
+
+impl<T: Eq + Hash, S: BuildHasher> Extend<T> for IdealHashSet<T, S> {
+	#[inline]
+	fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {self.0.extend(iter)}
+}
+
+impl<T: Eq + Hash, S: BuildHasher + Default> From<IdealVec<T>> for IdealHashSet<T, S> {
+	fn from(v: IdealVec<T>) -> Self {Self(HashSet::from_iter(v.0))}
+}
+
+impl<T, S> IntoIterator for IdealHashSet<T, S> {
+	type Item = T;
+	type IntoIter = IntoIter<T>;
+	#[inline]
+	fn into_iter(self) -> Self::IntoIter {
+		self.0.into_iter()
+	}
+}
+
+impl<'a, T, S> IntoIterator for &'a IdealHashSet<T, S> {
+	type Item = &'a T;
+	type IntoIter = Iter<'a, T>;
+	#[inline]
+	fn into_iter(self) -> Self::IntoIter {
+		self.0.iter()
+	}
+}
+
+impl<T: Eq + Hash, S: BuildHasher> PartialEq<HashSet<T, S>> for IdealHashSet<T, S> {
+	#[inline]
+	fn eq(&self, other: &HashSet<T, S>) -> bool {self.0 == *other}
+}
+
+impl<T: Eq + Hash, S: BuildHasher> PartialEq<Self> for IdealHashSet<T, S> {
+	#[inline]
+	fn eq(&self, other: &Self) -> bool {self.0 == other.0}
+}
\ No newline at end of file
@@ -0,0 +1,168 @@
+use core::{
+	cmp::Ordering,
+	iter::{
+		Chain,
+		Cloned,
+		Copied,
+		Enumerate,
+		FromIterator,
+		Inspect,
+		Map,
+		Peekable,
+		Rev,
+		Zip,
+	},
+	num::NonZeroUsize,
+};
+use alloc::vec::Vec;
+use super::{
+	error::*,
+	vec::*,
+};
+
+#[inline]
+pub(crate) unsafe fn unwrap_unchecked<T>(option: Option<T>) -> T {
+	match option {
+		Some(value) => value,
+		None => core::hint::unreachable_unchecked(),
+	}
+}
+
+/// An iterator that yields at least one item.
+///
+/// It is not an `Iterator` itself, since advancing it could break the invariant; use `into_iter` to get at the items.
+#[derive(Clone, Debug)]
+pub struct IdealIter<I>(pub(crate) I);
+
+impl<I: Iterator> IdealIter<I> {
+	#[inline]
+	pub unsafe fn new_unchecked(iter: I) -> Self {
+		Self(iter)
+	}
+
+	pub fn new(iter: I) -> Result<IdealIter<Peekable<I>>, EmptyError<Peekable<I>>> {
+		let mut iter = iter.peekable();
+		if iter.peek().is_some() {
+			Ok(IdealIter(iter))
+		} else {
+			Err(EmptyError(iter))
+		}
+	}
+
+	#[inline]
+	pub fn get(self) -> I {
+		self.0
+	}
+}
+
+impl<I: Iterator> IntoIterator for IdealIter<I> {
+	type Item = I::Item;
+	type IntoIter = I;
+	#[inline]
+	fn into_iter(self) -> Self::IntoIter {
+		self.0
+	}
+}
+
+unsafe impl<I: Iterator> NonEmptyIterator for IdealIter<I> {}
+
+/// An `IntoIterator` whose iterator yields at least one item.
+///
+/// Implementors must guarantee that the first call to `next` on the result of `into_iter` returns `Some`.
+pub unsafe trait NonEmptyIterator: IntoIterator + Sized {
+	#[inline]
+	fn first(self) -> Self::Item {
+		unsafe {unwrap_unchecked(self.into_iter().next())}
+	}
+
+	#[inline]
+	fn last(self) -> Self::Item {
+		unsafe {unwrap_unchecked(self.into_iter().last())}
+	}
+
+	#[inline]
+	fn count(self) -> NonZeroUsize {
+		unsafe {NonZeroUsize::new_unchecked(self.into_iter().count())}
+	}
+
+	fn reduce<F: FnMut(Self::Item, Self::Item) -> Self::Item>(self, f: F) -> Self::Item {
+		let mut iter = self.into_iter();
+		let first = unsafe {unwrap_unchecked(iter.next())};
+		iter.fold(first, f)
+	}
+
+	#[inline]
+	fn max(self) -> Self::Item where Self::Item: Ord {
+		unsafe {unwrap_unchecked(self.into_iter().max())}
+	}
+
+	#[inline]
+	fn min(self) -> Self::Item where Self::Item: Ord {
+		unsafe {unwrap_unchecked(self.into_iter().min())}
+	}
+
+	#[inline]
+	fn max_by<F: FnMut(&Self::Item, &Self::Item) -> Ordering>(self, compare: F) -> Self::Item {
+		unsafe {unwrap_unchecked(self.into_iter().max_by(compare))}
+	}
+
+	#[inline]
+	fn min_by<F: FnMut(&Self::Item, &Self::Item) -> Ordering>(self, compare: F) -> Self::Item {
+		unsafe {unwrap_unchecked(self.into_iter().min_by(compare))}
+	}
+
+	#[inline]
+	fn max_by_key<B: Ord, F: FnMut(&Self::Item) -> B>(self, f: F) -> Self::Item {
+		unsafe {unwrap_unchecked(self.into_iter().max_by_key(f))}
+	}
+
+	#[inline]
+	fn min_by_key<B: Ord, F: FnMut(&Self::Item) -> B>(self, f: F) -> Self::Item {
+		unsafe {unwrap_unchecked(self.into_iter().min_by_key(f))}
+	}
+
+	#[inline]
+	fn map<B, F: FnMut(Self::Item) -> B>(self, f: F) -> IdealIter<Map<Self::IntoIter, F>> {
+		IdealIter(self.into_iter().map(f))
+	}
+
+	#[inline]
+	fn enumerate(self) -> IdealIter<Enumerate<Self::IntoIter>> {
+		IdealIter(self.into_iter().enumerate())
+	}
+
+	#[inline]
+	fn zip<J: NonEmptyIterator>(self, other: J) -> IdealIter<Zip<Self::IntoIter, J::IntoIter>> {
+		IdealIter(self.into_iter().zip(other))
+	}
+
+	#[inline]
+	fn chain<J: IntoIterator<Item = Self::Item>>(self, other: J) -> IdealIter<Chain<Self::IntoIter, J::IntoIter>> {
+		IdealIter(self.into_iter().chain(other))
+	}
+
+	#[inline]
+	fn rev(self) -> IdealIter<Rev<Self::IntoIter>> where Self::IntoIter: DoubleEndedIterator {
+		IdealIter(self.into_iter().rev())
+	}
+
+	#[inline]
+	fn cloned<'a, T: 'a + Clone>(self) -> IdealIter<Cloned<Self::IntoIter>> where Self: NonEmptyIterator<Item = &'a T> {
+		IdealIter(self.into_iter().cloned())
+	}
+
+	#[inline]
+	fn copied<'a, T: 'a + Copy>(self) -> IdealIter<Copied<Self::IntoIter>> where Self: NonEmptyIterator<Item = &'a T> {
+		IdealIter(self.into_iter().copied())
+	}
+
+	#[inline]
+	fn inspect<F: FnMut(&Self::Item)>(self, f: F) -> IdealIter<Inspect<Self::IntoIter, F>> {
+		IdealIter(self.into_iter().inspect(f))
+	}
+
+	#[inline]
+	fn collect_ideal(self) -> IdealVec<Self::Item> {
+		IdealVec(Vec::from_iter(self.into_iter()))
+	}
+}
\ No newline at end of file
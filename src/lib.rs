@@ -1,10 +1,33 @@
 #![no_std]
-#![feature(const_generics, const_evaluatable_checked, generic_associated_types, never_type, arbitrary_self_types, toowned_clone_into, extend_one)]
+#![feature(const_generics, const_evaluatable_checked, generic_associated_types, never_type, arbitrary_self_types, toowned_clone_into, extend_one, slice_concat_trait, array_map, const_slice_from_ref, const_raw_ptr_deref, const_mut_refs, map_first_last, map_into_keys_values)]
+#![cfg_attr(all(feature = "std", not(feature = "hashbrown")), feature(hash_drain_filter))]
 #![allow(incomplete_features)]
 
 extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
+#[macro_use]
+mod macros;
+#[doc(hidden)]
+pub use macros::__private;
 
 pub mod pointer_class;
 pub mod array;
+pub mod binary_heap;
+pub mod btree_map;
+pub mod btree_set;
+pub mod error;
+#[cfg(any(feature = "std", feature = "hashbrown"))]
+pub mod hash_map;
+#[cfg(any(feature = "std", feature = "hashbrown"))]
+pub mod hash_set;
+pub mod iter;
+pub mod multi_map;
 pub mod slice;
-pub mod vec;
\ No newline at end of file
+pub mod str;
+pub mod string;
+pub mod these;
+pub mod validated;
+pub mod vec;
+pub mod vec_deque;
\ No newline at end of file
@@ -0,0 +1,65 @@
+/// Creates an `IdealVec` from a non-empty list of elements, or from an element and a count.
+///
+/// A literal count is checked at compile time; any other count must be a `NonZeroUsize`.
+#[macro_export]
+macro_rules! ideal_vec {
+	() => {
+		compile_error!("ideal_vec! requires at least one element")
+	};
+	($elem:expr; $n:literal) => {
+		$crate::vec::IdealVec::from_elem($elem, {
+			const N: $crate::__private::NonZeroUsize = $crate::__private::nonzero($n);
+			N
+		})
+	};
+	($elem:expr; $n:expr) => {
+		$crate::vec::IdealVec::from_elem($elem, $n)
+	};
+	($($x:expr),+ $(,)?) => {{
+		let vec = $crate::__private::vec![$($x),+];
+		unsafe {$crate::vec::IdealVec::new_unchecked(vec)}
+	}};
+}
+
+/// Creates an `IdealArray` from a non-empty list of elements, or from an element and a literal count.
+#[macro_export]
+macro_rules! ideal_array {
+	() => {
+		compile_error!("ideal_array! requires at least one element")
+	};
+	($elem:expr; $n:literal) => {
+		$crate::array::IdealArray::<_, {$crate::__private::nonzero($n)}>::new([$elem; $n])
+	};
+	($($x:expr),+ $(,)?) => {
+		$crate::array::IdealArray::<_, {$crate::__private::nonzero(<[&str]>::len(&[$(stringify!($x)),+]))}>::new([$($x),+])
+	};
+}
+
+/// Creates a `&'static IdealSlice` from a non-empty list of constant elements.
+#[macro_export]
+macro_rules! ideal_slice {
+	() => {
+		compile_error!("ideal_slice! requires at least one element")
+	};
+	($($x:expr),+ $(,)?) => {{
+		let slice: &'static [_] = &[$($x),+];
+		unsafe {$crate::slice::IdealSlice::new_ref_unchecked(slice)}
+	}};
+}
+
+#[doc(hidden)]
+pub mod __private {
+	pub use alloc::vec;
+	pub use core::num::NonZeroUsize;
+
+	/// Fails const evaluation when `n` is zero.
+	pub const fn nonzero(n: usize) -> NonZeroUsize {
+		match NonZeroUsize::new(n) {
+			Some(n) => n,
+			None => {
+				let empty: [NonZeroUsize; 0] = [];
+				empty[n]
+			},
+		}
+	}
+}
\ No newline at end of file
@@ -0,0 +1,178 @@
+use core::{
+	borrow::Borrow,
+	fmt::{
+		self,
+		Debug,
+	},
+	hash::{
+		self,
+		Hash,
+	},
+	num::NonZeroUsize,
+};
+use alloc::{
+	collections::btree_map::{
+		self,
+		BTreeMap,
+		Entry,
+	},
+	vec,
+};
+use super::{
+	iter::*,
+	slice::*,
+	vec::*,
+};
+
+/// A map from keys to non-empty groups of values.
+///
+/// A key is present exactly as long as its group holds at least one value.
+pub struct IdealMultiMap<K, V>(pub(crate) BTreeMap<K, IdealVec<V>>);
+
+impl<K, V> IdealMultiMap<K, V> {
+	#[inline]
+	pub fn new() -> Self {
+		Self(BTreeMap::new())
+	}
+
+	pub fn get_ref<'a>(&'a self) -> &'a BTreeMap<K, IdealVec<V>> {
+		&self.0
+	}
+
+	/// Returns the number of keys.
+	#[inline]
+	pub fn len(&self) -> usize {
+		self.0.len()
+	}
+
+	#[inline]
+	pub fn is_empty(&self) -> bool {
+		self.0.is_empty()
+	}
+
+	#[inline]
+	pub fn iter<'a>(&'a self) -> btree_map::Iter<'a, K, IdealVec<V>> {
+		self.0.iter()
+	}
+
+	#[inline]
+	pub fn keys<'a>(&'a self) -> btree_map::Keys<'a, K, IdealVec<V>> {
+		self.0.keys()
+	}
+
+	#[inline]
+	pub fn groups<'a>(&'a self) -> btree_map::Values<'a, K, IdealVec<V>> {
+		self.0.values()
+	}
+}
+
+impl<K: Ord, V> IdealMultiMap<K, V> {
+	pub fn group_by_key<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+		let mut map = Self::new();
+		map.extend(iter);
+		map
+	}
+
+	#[inline]
+	pub fn get<Q: ?Sized + Ord>(&self, key: &Q) -> Option<&IdealSlice<V>> where K: Borrow<Q> {
+		self.0.get(key).map(|group| group.as_ideal_slice())
+	}
+
+	/// Mutably borrows the values for `key`; the group cannot be emptied through the borrow.
+	#[inline]
+	pub fn get_mut<Q: ?Sized + Ord>(&mut self, key: &Q) -> Option<&mut IdealSlice<V>> where K: Borrow<Q> {
+		self.0.get_mut(key).map(|group| &mut **group)
+	}
+
+	#[inline]
+	pub fn contains_key<Q: ?Sized + Ord>(&self, key: &Q) -> bool where K: Borrow<Q> {
+		self.0.contains_key(key)
+	}
+
+	/// Returns the number of values for `key`, or `None` if it is absent.
+	#[inline]
+	pub fn count<Q: ?Sized + Ord>(&self, key: &Q) -> Option<NonZeroUsize> where K: Borrow<Q> {
+		self.0.get(key).map(|group| group.len())
+	}
+
+	/// Appends `value` to the group for `key`, creating the group if needed.
+	pub fn insert(&mut self, key: K, value: V) {
+		match self.0.entry(key) {
+			Entry::Vacant(entry) => {entry.insert(IdealVec(vec![value]));},
+			Entry::Occupied(mut entry) => entry.get_mut().push(value),
+		}
+	}
+
+	/// Removes the last value for `key`, dropping the key if it was the only one.
+	pub fn remove_one<Q: ?Sized + Ord>(&mut self, key: &Q) -> Option<V> where K: Borrow<Q> {
+		let group = self.0.get_mut(key)?;
+		match group.pop_if_many() {
+			Some(value) => Some(value),
+			None => self.0.remove(key).map(|group| group.into_ideal_iter().first()),
+		}
+	}
+
+	/// Removes the whole group for `key`.
+	#[inline]
+	pub fn remove_all<Q: ?Sized + Ord>(&mut self, key: &Q) -> Option<IdealVec<V>> where K: Borrow<Q> {
+		self.0.remove(key)
+	}
+}
+
+impl<K: Clone, V: Clone> Clone for IdealMultiMap<K, V> {
+	fn clone(&self) -> Self {
+		Self(self.0.clone())
+	}
+}
+
+impl<K: Debug, V: Debug> Debug for IdealMultiMap<K, V> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {self.0.fmt(f)}
+}
+
+impl<K, V> Default for IdealMultiMap<K, V> {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl<K: Eq, V: Eq> Eq for IdealMultiMap<K, V> {}
+
+impl<K: Ord, V> Extend<(K, V)> for IdealMultiMap<K, V> {
+	fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+		for (key, value) in iter {
+			self.insert(key, value);
+		}
+	}
+}
+
+impl<K, V> From<IdealMultiMap<K, V>> for BTreeMap<K, IdealVec<V>> {
+	fn from(map: IdealMultiMap<K, V>) -> Self {map.0}
+}
+
+impl<K: Hash, V: Hash> Hash for IdealMultiMap<K, V> {
+	#[inline]
+	fn hash<H: hash::Hasher>(&self, state: &mut H) {self.0.hash(state)}
+}
+
+impl<K, V> IntoIterator for IdealMultiMap<K, V> {
+	type Item = (K, IdealVec<V>);
+	type IntoIter = btree_map::IntoIter<K, IdealVec<V>>;
+	#[inline]
+	fn into_iter(self) -> Self::IntoIter {
+		self.0.into_iter()
+	}
+}
+
+impl<'a, K, V> IntoIterator for &'a IdealMultiMap<K, V> {
+	type Item = (&'a K, &'a IdealVec<V>);
+	type IntoIter = btree_map::Iter<'a, K, IdealVec<V>>;
+	#[inline]
+	fn into_iter(self) -> Self::IntoIter {
+		self.0.iter()
+	}
+}
+
+impl<K: PartialEq, V: PartialEq> PartialEq<Self> for IdealMultiMap<K, V> {
+	#[inline]
+	fn eq(&self, other: &Self) -> bool {self.0 == other.0}
+}
\ No newline at end of file
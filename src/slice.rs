@@ -1,4 +1,5 @@
 use core::{
+	borrow::Borrow,
 	cmp::Ordering,
 	fmt::{
 		self,
@@ -8,15 +9,25 @@ use core::{
 		self,
 		Hash,
 	},
+	iter::FusedIterator,
 	num::NonZeroUsize,
 	ops::{
+		Bound,
 		Index,
 		IndexMut,
+		Range,
+		RangeFrom,
+		RangeFull,
+		RangeInclusive,
+		RangeTo,
+		RangeToInclusive,
 	},
 	slice::{
 		Iter,
 		IterMut,
 		SliceIndex,
+		Split,
+		SplitInclusive,
 	},
 };
 use alloc::{
@@ -26,18 +37,53 @@ use alloc::{
 	},
 	boxed::Box,
 	rc::Rc,
+	slice::{
+		Concat,
+		Join,
+	},
+	string::String,
 	sync::Arc,
 	vec::Vec,
 };
 use super::{
 	array::*,
+	error::*,
+	iter::*,
 	pointer_class::*,
+	str::*,
+	string::*,
 	vec::*,
 };
 
 pub struct IdealSlice<T>(pub(crate) [T]);
 
+/// Forwards indexing to `[T]` for the index types whose result may be empty.
+macro_rules! impl_index_slice {
+	($($index:ty),+) => {$(
+		impl<T> Index<$index> for IdealSlice<T> {
+			type Output = <$index as SliceIndex<[T]>>::Output;
+			#[inline]
+			fn index(&self, index: $index) -> &Self::Output {self.0.index(index)}
+		}
+
+		impl<T> IndexMut<$index> for IdealSlice<T> {
+			#[inline]
+			fn index_mut(&mut self, index: $index) -> &mut Self::Output {self.0.index_mut(index)}
+		}
+	)+};
+}
+
 impl<T> IdealSlice<T> {
+	#[inline]
+	pub const fn from_ref<'a>(value: &'a T) -> &'a Self {
+		unsafe {&*(core::slice::from_ref(value) as *const [T] as *const Self)}
+	}
+
+	#[inline]
+	pub const fn from_mut<'a>(value: &'a mut T) -> &'a mut Self {
+		unsafe {&mut *(core::slice::from_mut(value) as *mut [T] as *mut Self)}
+	}
+
 	#[inline]
 	pub unsafe fn new_ref_unchecked<'a>(slice: &'a [T]) -> &'a Self {
 		generic_transmute(slice)
@@ -59,41 +105,57 @@ impl<T> IdealSlice<T> {
 	}
 
 	#[inline]
-	pub fn new_ref<'a>(slice: &'a [T]) -> Option<&'a Self> {
+	pub fn new_ref<'a>(slice: &'a [T]) -> Result<&'a Self, EmptyError<&'a [T]>> {
 		if slice.len() != 0 {
-			unsafe {Some(generic_transmute(slice))}
+			unsafe {Ok(generic_transmute(slice))}
 		} else {
-			None
+			Err(EmptyError(slice))
 		}
 	}
 
 	#[inline]
-	pub fn new_mut<'a>(slice: &'a mut [T]) -> Option<&'a mut Self> {
+	pub fn new_mut<'a>(slice: &'a mut [T]) -> Result<&'a mut Self, EmptyError<&'a mut [T]>> {
 		if slice.len() != 0 {
-			unsafe {Some(generic_transmute(slice))}
+			unsafe {Ok(generic_transmute(slice))}
 		} else {
-			None
+			Err(EmptyError(slice))
 		}
 	}
 
 	#[inline]
-	pub fn new_ptr<'a, P: PointerClass>(slice: P::Ptr<[T]>) -> Option<P::Ptr<Self>> {
+	pub fn new_ptr<'a, P: PointerClass>(slice: P::Ptr<[T]>) -> Result<P::Ptr<Self>, EmptyError<P::Ptr<[T]>>> {
 		if slice.len() != 0 {
-			unsafe {Some(generic_transmute(slice))}
+			unsafe {Ok(generic_transmute(slice))}
 		} else {
-			None
+			Err(EmptyError(slice))
 		}
 	}
 
 	#[inline]
-	pub fn new_cow<'a>(slice: Cow<'a, [T]>) -> Option<Cow<'a, Self>> where [T]: ToOwned, Self: ToOwned {
+	pub fn new_cow<'a>(slice: Cow<'a, [T]>) -> Result<Cow<'a, Self>, EmptyError<Cow<'a, [T]>>> where [T]: ToOwned, Self: ToOwned {
 		if slice.len() != 0 {
-			unsafe {Some(generic_transmute(slice))}
+			unsafe {Ok(generic_transmute(slice))}
 		} else {
-			None
+			Err(EmptyError(slice))
 		}
 	}
 
+	#[inline]
+	#[track_caller]
+	pub fn expect_ref<'a>(slice: &'a [T]) -> &'a Self {
+		Self::new_ref(slice).expect("expected a non-empty slice")
+	}
+
+	#[inline]
+	#[track_caller]
+	pub fn expect_mut<'a>(slice: &'a mut [T]) -> &'a mut Self {
+		Self::new_mut(slice).expect("expected a non-empty slice")
+	}
+
+	pub fn singleton_ptr<P: PointerClass>(value: T) -> P::Ptr<Self> where P::Ptr<[T]>: From<Vec<T>> {
+		unsafe {Self::new_ptr_unchecked::<P>(P::Ptr::<[T]>::from(alloc::vec![value]))}
+	}
+
 	#[inline]
 	pub fn get_ref<'a>(self: &'a Self) -> &'a [T] {
 		unsafe {generic_transmute(self)}
@@ -119,15 +181,358 @@ impl<T> IdealSlice<T> {
 		unsafe {NonZeroUsize::new_unchecked(self.0.len())}
 	}
 
+	#[inline]
+	pub fn first(&self) -> &T {
+		unsafe {self.0.get_unchecked(0)}
+	}
+
+	#[inline]
+	pub fn first_mut(&mut self) -> &mut T {
+		unsafe {self.0.get_unchecked_mut(0)}
+	}
+
+	#[inline]
+	pub fn last(&self) -> &T {
+		unsafe {self.0.get_unchecked(self.0.len() - 1)}
+	}
+
+	#[inline]
+	pub fn last_mut(&mut self) -> &mut T {
+		let last = self.0.len() - 1;
+		unsafe {self.0.get_unchecked_mut(last)}
+	}
+
+	#[inline]
+	pub fn split_first(&self) -> (&T, &[T]) {
+		unsafe {unwrap_unchecked(self.0.split_first())}
+	}
+
+	#[inline]
+	pub fn split_first_mut(&mut self) -> (&mut T, &mut [T]) {
+		unsafe {unwrap_unchecked(self.0.split_first_mut())}
+	}
+
+	#[inline]
+	pub fn split_last(&self) -> (&[T], &T) {
+		unsafe {
+			let (last, init) = unwrap_unchecked(self.0.split_last());
+			(init, last)
+		}
+	}
+
+	#[inline]
+	pub fn split_last_mut(&mut self) -> (&mut [T], &mut T) {
+		unsafe {
+			let (last, init) = unwrap_unchecked(self.0.split_last_mut());
+			(init, last)
+		}
+	}
+
+	#[inline]
+	pub fn get_ideal<I: SliceIndex<[T], Output = [T]>>(&self, index: I) -> Option<&Self> {
+		self.0.get(index).and_then(|slice| Self::new_ref(slice).ok())
+	}
+
+	#[inline]
+	pub fn get_ideal_mut<I: SliceIndex<[T], Output = [T]>>(&mut self, index: I) -> Option<&mut Self> {
+		self.0.get_mut(index).and_then(|slice| Self::new_mut(slice).ok())
+	}
+
+	/// Panics if `mid > len`.
+	#[inline]
+	pub fn split_at_nonzero(&self, mid: NonZeroUsize) -> (&Self, &[T]) {
+		let (head, tail) = self.0.split_at(mid.get());
+		unsafe {(Self::new_ref_unchecked(head), tail)}
+	}
+
+	/// Panics if `mid > len`.
+	#[inline]
+	pub fn split_at_nonzero_mut(&mut self, mid: NonZeroUsize) -> (&mut Self, &mut [T]) {
+		let (head, tail) = self.0.split_at_mut(mid.get());
+		unsafe {(Self::new_mut_unchecked(head), tail)}
+	}
+
+	/// Like `[T]::split_at`, but returns each half only if it is non-empty; at least one always is.
+	///
+	/// Panics if `mid > len`.
+	#[inline]
+	pub fn split_at(&self, mid: usize) -> (Option<&Self>, Option<&Self>) {
+		let (head, tail) = self.0.split_at(mid);
+		(Self::new_ref(head).ok(), Self::new_ref(tail).ok())
+	}
+
+	/// Panics if `mid > len`.
+	#[inline]
+	pub fn split_at_mut(&mut self, mid: usize) -> (Option<&mut Self>, Option<&mut Self>) {
+		let (head, tail) = self.0.split_at_mut(mid);
+		(Self::new_mut(head).ok(), Self::new_mut(tail).ok())
+	}
+
+	#[inline]
+	pub fn iter<'a>(&'a self) -> IdealIter<Iter<'a, T>> {
+		IdealIter(self.0.iter())
+	}
+
+	#[inline]
+	pub fn iter_mut<'a>(&'a mut self) -> IdealIter<IterMut<'a, T>> {
+		IdealIter(self.0.iter_mut())
+	}
+
+	#[inline]
+	pub fn chunks<'a>(&'a self, size: NonZeroUsize) -> IdealIter<Chunks<'a, T>> {
+		IdealIter(Chunks(self.0.chunks(size.get())))
+	}
+
+	#[inline]
+	pub fn chunks_mut<'a>(&'a mut self, size: NonZeroUsize) -> IdealIter<ChunksMut<'a, T>> {
+		IdealIter(ChunksMut(self.0.chunks_mut(size.get())))
+	}
+
+	#[inline]
+	pub fn rchunks<'a>(&'a self, size: NonZeroUsize) -> IdealIter<RChunks<'a, T>> {
+		IdealIter(RChunks(self.0.rchunks(size.get())))
+	}
+
+	#[inline]
+	pub fn rchunks_mut<'a>(&'a mut self, size: NonZeroUsize) -> IdealIter<RChunksMut<'a, T>> {
+		IdealIter(RChunksMut(self.0.rchunks_mut(size.get())))
+	}
+
+	/// Yields no chunks if `size > len`, in which case the whole slice is the remainder.
+	#[inline]
+	pub fn chunks_exact<'a>(&'a self, size: NonZeroUsize) -> ChunksExact<'a, T> {
+		ChunksExact(self.0.chunks_exact(size.get()))
+	}
+
+	/// Groups runs of consecutive elements for which `predicate` holds between each neighbouring pair.
+	#[inline]
+	pub fn chunk_by<'a, F: FnMut(&T, &T) -> bool>(&'a self, predicate: F) -> IdealIter<ChunkBy<'a, T, F>> {
+		IdealIter(ChunkBy {slice: &self.0, predicate})
+	}
+
+	/// Like `[T]::split`, which always yields at least one, possibly empty, piece.
+	#[inline]
+	pub fn split<'a, F: FnMut(&T) -> bool>(&'a self, predicate: F) -> IdealIter<Split<'a, T, F>> {
+		IdealIter(self.0.split(predicate))
+	}
+
+	#[inline]
+	pub fn split_inclusive<'a, F: FnMut(&T) -> bool>(&'a self, predicate: F) -> IdealIter<SplitInclusive<'a, T, F>> {
+		IdealIter(self.0.split_inclusive(predicate))
+	}
+
+	/// Returns `None` if `size > len`, as there would be no windows.
+	#[inline]
+	pub fn windows<'a>(&'a self, size: NonZeroUsize) -> Option<IdealIter<Windows<'a, T>>> {
+		if size <= self.len() {
+			Some(IdealIter(Windows(self.0.windows(size.get()))))
+		} else {
+			None
+		}
+	}
+
+	pub fn reduce<F: FnMut(T, &T) -> T>(&self, f: F) -> T where T: Clone {
+		let (first, rest) = self.split_first();
+		rest.iter().fold(first.clone(), f)
+	}
+
+	/// Returns the last maximum. Named to avoid being shadowed by `Ord::max` on owning wrappers.
+	#[inline]
+	pub fn maximum(&self) -> &T where T: Ord {
+		self.iter().max()
+	}
+
+	/// Returns the first minimum.
+	#[inline]
+	pub fn minimum(&self) -> &T where T: Ord {
+		self.iter().min()
+	}
+
+	#[inline]
+	pub fn max_by<F: FnMut(&T, &T) -> Ordering>(&self, mut compare: F) -> &T {
+		self.iter().max_by(|a, b| compare(a, b))
+	}
+
+	#[inline]
+	pub fn min_by<F: FnMut(&T, &T) -> Ordering>(&self, mut compare: F) -> &T {
+		self.iter().min_by(|a, b| compare(a, b))
+	}
+
+	#[inline]
+	pub fn max_by_key<B: Ord, F: FnMut(&T) -> B>(&self, mut f: F) -> &T {
+		self.iter().max_by_key(|x| f(x))
+	}
+
+	#[inline]
+	pub fn min_by_key<B: Ord, F: FnMut(&T) -> B>(&self, mut f: F) -> &T {
+		self.iter().min_by_key(|x| f(x))
+	}
+
+	/// Returns the minimum and maximum in a single pass, with the same tie-breaking as `minimum` and `maximum`.
+	pub fn minmax(&self) -> (&T, &T) where T: Ord {
+		let (first, rest) = self.split_first();
+		rest.iter().fold((first, first), |(min, max), x| {
+			(if x < min {x} else {min}, if x >= max {x} else {max})
+		})
+	}
+
+	/// Returns the index of the element `maximum` would return, that is, the last maximum.
+	pub fn argmax(&self) -> usize where T: Ord {
+		self.iter().enumerate().max_by(|(_, a), (_, b)| a.cmp(b)).0
+	}
+
+	/// Returns the index of the element `minimum` would return, that is, the first minimum.
+	pub fn argmin(&self) -> usize where T: Ord {
+		self.iter().enumerate().min_by(|(_, a), (_, b)| a.cmp(b)).0
+	}
+
+	/// Returns every element equal to the maximum, in order.
+	pub fn maxima(&self) -> IdealVec<&T> where T: Ord {
+		let max = self.maximum();
+		IdealVec(self.0.iter().filter(|&x| x == max).collect())
+	}
+
+	/// Returns every element equal to the minimum, in order.
+	pub fn minima(&self) -> IdealVec<&T> where T: Ord {
+		let min = self.minimum();
+		IdealVec(self.0.iter().filter(|&x| x == min).collect())
+	}
+
+	#[inline]
+	pub fn concat<Item: ?Sized>(&self) -> <Self as Concat<Item>>::Output where Self: Concat<Item> {
+		Concat::concat(self)
+	}
+
+	#[inline]
+	pub fn join<Separator>(&self, sep: Separator) -> <Self as Join<Separator>>::Output where Self: Join<Separator> {
+		Join::join(self, sep)
+	}
+
 	pub fn to_ideal_vec(&self) -> IdealVec<T> where T: Clone {
 		IdealVec(self.0.to_vec())
 	}
 
+	/// Replaces each element with `f` of it; if `f` panics, the elements not yet visited are left as they were.
+	pub fn map_in_place<F: FnMut(&T) -> T>(&mut self, mut f: F) {
+		for value in self.0.iter_mut() {
+			*value = f(value);
+		}
+	}
+
+	pub fn map_to_vec<U, F: FnMut(&T) -> U>(&self, f: F) -> IdealVec<U> {
+		IdealVec(self.0.iter().map(f).collect())
+	}
+
+	pub fn try_map_to_vec<U, E, F: FnMut(&T) -> Result<U, E>>(&self, f: F) -> Result<IdealVec<U>, E> {
+		self.0.iter().map(f).collect::<Result<_, _>>().map(IdealVec)
+	}
+
 	pub fn into_ideal_vec(self: Box<Self>) -> IdealVec<T> {
 		IdealVec(self.get_ptr::<WithBox>().into_vec())
 	}
 }
 
+impl<T> IdealSlice<Option<T>> {
+	pub fn transpose(self: Box<Self>) -> Option<Box<IdealSlice<T>>> {
+		self.into_ideal_vec().transpose().map(IdealVec::into_boxed_ideal_slice)
+	}
+}
+
+impl<T, E> IdealSlice<Result<T, E>> {
+	/// Stops at the first error.
+	pub fn transpose(self: Box<Self>) -> Result<Box<IdealSlice<T>>, E> {
+		self.into_ideal_vec().transpose().map(IdealVec::into_boxed_ideal_slice)
+	}
+
+	/// Like `transpose`, but collects every error.
+	pub fn transpose_all(self: Box<Self>) -> Result<Box<IdealSlice<T>>, IdealVec<E>> {
+		self.into_ideal_vec().transpose_all().map(IdealVec::into_boxed_ideal_slice)
+	}
+}
+
+/// Wraps a slice iterator whose items are never empty, yielding them as `IdealSlice`s.
+macro_rules! ideal_slice_iter {
+	($name:ident, $inner:ident, $item:ty, $new:ident) => {
+		#[derive(Debug)]
+		pub struct $name<'a, T>(core::slice::$inner<'a, T>);
+
+		impl<'a, T> Iterator for $name<'a, T> {
+			type Item = $item;
+			#[inline]
+			fn next(&mut self) -> Option<$item> {
+				self.0.next().map(|slice| unsafe {IdealSlice::$new(slice)})
+			}
+
+			#[inline]
+			fn size_hint(&self) -> (usize, Option<usize>) {self.0.size_hint()}
+		}
+
+		impl<'a, T> DoubleEndedIterator for $name<'a, T> {
+			#[inline]
+			fn next_back(&mut self) -> Option<$item> {
+				self.0.next_back().map(|slice| unsafe {IdealSlice::$new(slice)})
+			}
+		}
+
+		impl<'a, T> ExactSizeIterator for $name<'a, T> {}
+
+		impl<'a, T> FusedIterator for $name<'a, T> {}
+	};
+}
+
+ideal_slice_iter!(Chunks, Chunks, &'a IdealSlice<T>, new_ref_unchecked);
+ideal_slice_iter!(ChunksExact, ChunksExact, &'a IdealSlice<T>, new_ref_unchecked);
+ideal_slice_iter!(ChunksMut, ChunksMut, &'a mut IdealSlice<T>, new_mut_unchecked);
+ideal_slice_iter!(RChunks, RChunks, &'a IdealSlice<T>, new_ref_unchecked);
+ideal_slice_iter!(RChunksMut, RChunksMut, &'a mut IdealSlice<T>, new_mut_unchecked);
+ideal_slice_iter!(Windows, Windows, &'a IdealSlice<T>, new_ref_unchecked);
+
+pub struct ChunkBy<'a, T, F> {
+	slice: &'a [T],
+	predicate: F,
+}
+
+impl<'a, T: Debug, F> Debug for ChunkBy<'a, T, F> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.debug_struct("ChunkBy").field("slice", &self.slice).finish()
+	}
+}
+
+impl<'a, T, F: FnMut(&T, &T) -> bool> Iterator for ChunkBy<'a, T, F> {
+	type Item = &'a IdealSlice<T>;
+	fn next(&mut self) -> Option<&'a IdealSlice<T>> {
+		if self.slice.is_empty() {
+			return None;
+		}
+		let mut len = 1;
+		for pair in self.slice.windows(2) {
+			if (self.predicate)(&pair[0], &pair[1]) {
+				len += 1;
+			} else {
+				break;
+			}
+		}
+		let (head, tail) = self.slice.split_at(len);
+		self.slice = tail;
+		Some(unsafe {IdealSlice::new_ref_unchecked(head)})
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		((!self.slice.is_empty()) as usize, Some(self.slice.len()))
+	}
+}
+
+impl<'a, T, F: FnMut(&T, &T) -> bool> FusedIterator for ChunkBy<'a, T, F> {}
+
+impl<'a, T> ChunksExact<'a, T> {
+	/// Returns the elements left over after the last chunk, which may be none.
+	#[inline]
+	pub fn remainder(&self) -> &'a [T] {
+		self.0.remainder()
+	}
+}
+
 impl<T> AsMut<[T]> for IdealSlice<T> {
 	fn as_mut(&mut self) -> &mut [T] {&mut self.0}
 }
@@ -154,7 +559,29 @@ impl<T: Clone> Clone for Box<IdealSlice<T>> {
 	}
 }
 
-// TODO: Implement Concat (slice_concat_trait)
+impl<T: Clone, V: Borrow<IdealSlice<T>>> Concat<T> for IdealSlice<V> {
+	type Output = IdealVec<T>;
+	fn concat(slice: &Self) -> IdealVec<T> {
+		let size = slice.0.iter().map(|s| s.borrow().0.len()).sum();
+		let mut result = Vec::with_capacity(size);
+		for s in &slice.0 {
+			result.extend_from_slice(&s.borrow().0);
+		}
+		IdealVec(result)
+	}
+}
+
+impl<S: Borrow<IdealStr>> Concat<str> for IdealSlice<S> {
+	type Output = IdealString;
+	fn concat(slice: &Self) -> IdealString {
+		let size = slice.0.iter().map(|s| s.borrow().0.len()).sum();
+		let mut result = String::with_capacity(size);
+		for s in &slice.0 {
+			result.push_str(&s.borrow().0);
+		}
+		IdealString(result)
+	}
+}
 
 impl<T: Debug> Debug for IdealSlice<T> {
 	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {self.0.fmt(f)}
@@ -193,19 +620,34 @@ impl<T: Hash> Hash for IdealSlice<T> {
 	fn hash<H: hash::Hasher>(&self, state: &mut H) {self.0.hash(state)}
 }
 
-impl<T, I: SliceIndex<[T]>> Index<I> for IdealSlice<T>
-{
-	type Output = I::Output;
+impl<T> Index<RangeFull> for IdealSlice<T> {
+	type Output = Self;
 	#[inline]
-	fn index(&self, index: I) -> &I::Output {self.0.index(index)}
+	fn index(&self, _: RangeFull) -> &Self {self}
 }
 
-impl<T, I: SliceIndex<[T]>> IndexMut<I> for IdealSlice<T>
-{
+impl<T> Index<RangeToInclusive<usize>> for IdealSlice<T> {
+	type Output = Self;
 	#[inline]
-	fn index_mut(&mut self, index: I) -> &mut I::Output {self.0.index_mut(index)}
+	fn index(&self, index: RangeToInclusive<usize>) -> &Self {
+		unsafe {Self::new_ref_unchecked(self.0.index(index))}
+	}
 }
 
+impl<T> IndexMut<RangeFull> for IdealSlice<T> {
+	#[inline]
+	fn index_mut(&mut self, _: RangeFull) -> &mut Self {self}
+}
+
+impl<T> IndexMut<RangeToInclusive<usize>> for IdealSlice<T> {
+	#[inline]
+	fn index_mut(&mut self, index: RangeToInclusive<usize>) -> &mut Self {
+		unsafe {Self::new_mut_unchecked(self.0.index_mut(index))}
+	}
+}
+
+impl_index_slice!(usize, Range<usize>, RangeFrom<usize>, RangeInclusive<usize>, RangeTo<usize>, (Bound<usize>, Bound<usize>));
+
 impl<'a, T> IntoIterator for &'a IdealSlice<T> {
 	type Item = &'a T;
 	type IntoIter = Iter<'a, T>;
@@ -222,7 +664,54 @@ impl<'a, T> IntoIterator for &'a mut IdealSlice<T> {
 	}
 }
 
-// TODO: Implement Join (slice_concat_trait)
+impl<T: Clone, V: Borrow<IdealSlice<T>>> Join<&T> for IdealSlice<V> {
+	type Output = IdealVec<T>;
+	fn join(slice: &Self, sep: &T) -> IdealVec<T> {
+		let (first, rest) = slice.split_first();
+		let size = slice.0.iter().map(|s| s.borrow().0.len()).sum::<usize>() + rest.len();
+		let mut result = Vec::with_capacity(size);
+		result.extend_from_slice(&first.borrow().0);
+		for s in rest {
+			result.push(sep.clone());
+			result.extend_from_slice(&s.borrow().0);
+		}
+		IdealVec(result)
+	}
+}
+
+impl<T: Clone, V: Borrow<IdealSlice<T>>> Join<&[T]> for IdealSlice<V> {
+	type Output = IdealVec<T>;
+	fn join(slice: &Self, sep: &[T]) -> IdealVec<T> {
+		let (first, rest) = slice.split_first();
+		let size = slice.0.iter().map(|s| s.borrow().0.len()).sum::<usize>() + sep.len() * rest.len();
+		let mut result = Vec::with_capacity(size);
+		result.extend_from_slice(&first.borrow().0);
+		for s in rest {
+			result.extend_from_slice(sep);
+			result.extend_from_slice(&s.borrow().0);
+		}
+		IdealVec(result)
+	}
+}
+
+impl<S: Borrow<IdealStr>> Join<&str> for IdealSlice<S> {
+	type Output = IdealString;
+	fn join(slice: &Self, sep: &str) -> IdealString {
+		let (first, rest) = slice.split_first();
+		let size = slice.0.iter().map(|s| s.borrow().0.len()).sum::<usize>() + sep.len() * rest.len();
+		let mut result = String::with_capacity(size);
+		result.push_str(&first.borrow().0);
+		for s in rest {
+			result.push_str(sep);
+			result.push_str(&s.borrow().0);
+		}
+		IdealString(result)
+	}
+}
+
+unsafe impl<'a, T> NonEmptyIterator for &'a IdealSlice<T> {}
+
+unsafe impl<'a, T> NonEmptyIterator for &'a mut IdealSlice<T> {}
 
 impl<T: Ord> Ord for IdealSlice<T> {
 	#[inline]
@@ -0,0 +1,297 @@
+use core::{
+	cmp::Ordering,
+	fmt::{
+		self,
+		Debug,
+		Display,
+	},
+	hash::{
+		self,
+		Hash,
+	},
+	num::NonZeroUsize,
+	ops::{
+		Deref,
+		DerefMut,
+	},
+	str::{
+		Chars,
+		Utf8Error,
+	},
+};
+use alloc::{
+	borrow::{
+		Cow,
+		ToOwned,
+	},
+	boxed::Box,
+	rc::Rc,
+	string::String,
+	sync::Arc,
+};
+use super::{
+	error::*,
+	iter::*,
+	pointer_class::*,
+	slice::*,
+	string::*,
+};
+
+#[repr(transparent)]
+pub struct IdealStr(pub(crate) str);
+
+impl IdealStr {
+	#[inline]
+	pub unsafe fn new_ref_unchecked<'a>(s: &'a str) -> &'a Self {
+		generic_transmute(s)
+	}
+
+	#[inline]
+	pub unsafe fn new_mut_unchecked<'a>(s: &'a mut str) -> &'a mut Self {
+		generic_transmute(s)
+	}
+
+	#[inline]
+	pub unsafe fn new_ptr_unchecked<P: PointerClass>(s: P::Ptr<str>) -> P::Ptr<Self> {
+		generic_transmute(s)
+	}
+
+	#[inline]
+	pub unsafe fn new_cow_unchecked<'a>(s: Cow<'a, str>) -> Cow<'a, Self> {
+		generic_transmute(s)
+	}
+
+	#[inline]
+	pub fn new_ref<'a>(s: &'a str) -> Result<&'a Self, EmptyError<&'a str>> {
+		if s.len() != 0 {
+			unsafe {Ok(generic_transmute(s))}
+		} else {
+			Err(EmptyError(s))
+		}
+	}
+
+	#[inline]
+	pub fn new_mut<'a>(s: &'a mut str) -> Result<&'a mut Self, EmptyError<&'a mut str>> {
+		if s.len() != 0 {
+			unsafe {Ok(generic_transmute(s))}
+		} else {
+			Err(EmptyError(s))
+		}
+	}
+
+	#[inline]
+	pub fn new_ptr<P: PointerClass>(s: P::Ptr<str>) -> Result<P::Ptr<Self>, EmptyError<P::Ptr<str>>> {
+		if s.len() != 0 {
+			unsafe {Ok(generic_transmute(s))}
+		} else {
+			Err(EmptyError(s))
+		}
+	}
+
+	#[inline]
+	pub fn new_cow<'a>(s: Cow<'a, str>) -> Result<Cow<'a, Self>, EmptyError<Cow<'a, str>>> {
+		if s.len() != 0 {
+			unsafe {Ok(generic_transmute(s))}
+		} else {
+			Err(EmptyError(s))
+		}
+	}
+
+	#[inline]
+	#[track_caller]
+	pub fn expect_ref<'a>(s: &'a str) -> &'a Self {
+		Self::new_ref(s).expect("expected a non-empty str")
+	}
+
+	#[inline]
+	#[track_caller]
+	pub fn expect_mut<'a>(s: &'a mut str) -> &'a mut Self {
+		Self::new_mut(s).expect("expected a non-empty str")
+	}
+
+	#[inline]
+	pub fn from_utf8<'a>(v: &'a IdealSlice<u8>) -> Result<&'a Self, Utf8Error> {
+		core::str::from_utf8(&v.0).map(|s| unsafe {Self::new_ref_unchecked(s)})
+	}
+
+	#[inline]
+	pub fn get_ref<'a>(&'a self) -> &'a str {
+		&self.0
+	}
+
+	#[inline]
+	pub fn get_mut<'a>(&'a mut self) -> &'a mut str {
+		&mut self.0
+	}
+
+	#[inline]
+	pub fn get_ptr<P: PointerClass>(self: P::Ptr<Self>) -> P::Ptr<str> {
+		unsafe {generic_transmute(self)}
+	}
+
+	#[inline]
+	pub fn get_cow<'a>(self: Cow<'a, Self>) -> Cow<'a, str> {
+		unsafe {generic_transmute(self)}
+	}
+
+	#[inline]
+	pub const fn len(&self) -> NonZeroUsize {
+		unsafe {NonZeroUsize::new_unchecked(self.0.len())}
+	}
+
+	#[inline]
+	pub fn as_bytes(&self) -> &IdealSlice<u8> {
+		unsafe {IdealSlice::new_ref_unchecked(self.0.as_bytes())}
+	}
+
+	#[inline]
+	pub fn chars<'a>(&'a self) -> IdealIter<Chars<'a>> {
+		IdealIter(self.0.chars())
+	}
+
+	#[inline]
+	pub fn first_char(&self) -> char {
+		unsafe {unwrap_unchecked(self.0.chars().next())}
+	}
+
+	#[inline]
+	pub fn last_char(&self) -> char {
+		unsafe {unwrap_unchecked(self.0.chars().next_back())}
+	}
+
+	pub fn to_ideal_string(&self) -> IdealString {
+		IdealString(String::from(&self.0))
+	}
+
+	pub fn into_ideal_string(self: Box<Self>) -> IdealString {
+		IdealString(self.get_ptr::<WithBox>().into_string())
+	}
+}
+
+impl AsMut<str> for IdealStr {
+	fn as_mut(&mut self) -> &mut str {&mut self.0}
+}
+
+impl AsMut<Self> for IdealStr {
+	fn as_mut(&mut self) -> &mut Self {self}
+}
+
+impl AsRef<[u8]> for IdealStr {
+	fn as_ref(&self) -> &[u8] {self.0.as_bytes()}
+}
+
+impl AsRef<IdealSlice<u8>> for IdealStr {
+	fn as_ref(&self) -> &IdealSlice<u8> {self.as_bytes()}
+}
+
+impl AsRef<str> for IdealStr {
+	fn as_ref(&self) -> &str {&self.0}
+}
+
+impl AsRef<Self> for IdealStr {
+	fn as_ref(&self) -> &Self {self}
+}
+
+impl Clone for Box<IdealStr> {
+	fn clone(&self) -> Self {
+		self.to_ideal_string().into_boxed_ideal_str()
+	}
+}
+
+impl Debug for IdealStr {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {Debug::fmt(&self.0, f)}
+}
+
+impl Deref for IdealStr {
+	type Target = str;
+	#[inline]
+	fn deref(&self) -> &str {&self.0}
+}
+
+impl DerefMut for IdealStr {
+	#[inline]
+	fn deref_mut(&mut self) -> &mut str {&mut self.0}
+}
+
+impl Display for IdealStr {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {Display::fmt(&self.0, f)}
+}
+
+impl Eq for IdealStr {}
+
+impl From<IdealString> for Arc<IdealStr> {
+	#[inline]
+	fn from(s: IdealString) -> Self {
+		unsafe {IdealStr::new_ptr_unchecked::<WithArc>(Arc::from(s.0))}
+	}
+}
+
+impl From<IdealString> for Box<IdealStr> {
+	#[inline]
+	fn from(s: IdealString) -> Self {
+		s.into_boxed_ideal_str()
+	}
+}
+
+impl<'a> From<&'a IdealString> for Cow<'a, IdealStr> {
+	fn from(s: &'a IdealString) -> Self {
+		Cow::Borrowed(s.as_ideal_str())
+	}
+}
+
+impl From<IdealString> for Rc<IdealStr> {
+	#[inline]
+	fn from(s: IdealString) -> Self {
+		unsafe {IdealStr::new_ptr_unchecked::<WithRc>(Rc::from(s.0))}
+	}
+}
+
+impl Hash for IdealStr {
+	fn hash<H: hash::Hasher>(&self, state: &mut H) {self.0.hash(state)}
+}
+
+impl Ord for IdealStr {
+	#[inline]
+	fn cmp(&self, other: &Self) -> Ordering {self.0.cmp(&other.0)}
+}
+
+impl PartialEq<str> for IdealStr {
+	#[inline]
+	fn eq(&self, other: &str) -> bool {self.0 == *other}
+}
+
+impl<'a> PartialEq<&'a str> for IdealStr {
+	#[inline]
+	fn eq(&self, other: &&'a str) -> bool {self.0 == **other}
+}
+
+impl PartialEq<String> for IdealStr {
+	#[inline]
+	fn eq(&self, other: &String) -> bool {self.0 == **other}
+}
+
+impl PartialEq<Self> for IdealStr {
+	#[inline]
+	fn eq(&self, other: &Self) -> bool {self.0 == other.0}
+}
+
+impl PartialEq<IdealString> for IdealStr {
+	#[inline]
+	fn eq(&self, other: &IdealString) -> bool {self.0 == *other.0}
+}
+
+impl PartialOrd<str> for IdealStr {
+	#[inline]
+	fn partial_cmp(&self, other: &str) -> Option<Ordering> {self.0.partial_cmp(other)}
+}
+
+impl PartialOrd<Self> for IdealStr {
+	#[inline]
+	fn partial_cmp(&self, other: &Self) -> Option<Ordering> {self.0.partial_cmp(&other.0)}
+}
+
+impl ToOwned for IdealStr {
+	type Owned = IdealString;
+	fn to_owned(&self) -> Self::Owned {self.to_ideal_string()}
+	fn clone_into(&self, target: &mut IdealString) {self.0.clone_into(&mut target.0)}
+}
\ No newline at end of file
@@ -0,0 +1,292 @@
+use core::{
+	borrow::{
+		Borrow,
+		BorrowMut,
+	},
+	cmp::Ordering,
+	convert::TryFrom,
+	fmt::{
+		self,
+		Debug,
+		Display,
+		Write,
+	},
+	hash::{
+		self,
+		Hash,
+	},
+	num::NonZeroUsize,
+	ops::{
+		Deref,
+		DerefMut,
+	},
+};
+use alloc::{
+	borrow::Cow,
+	boxed::Box,
+	string::{
+		FromUtf8Error,
+		String,
+	},
+};
+use super::{
+	error::*,
+	pointer_class::*,
+	str::*,
+	vec::*,
+};
+
+#[repr(transparent)]
+pub struct IdealString(pub(crate) String);
+
+impl IdealString {
+	pub unsafe fn new_unchecked(s: String) -> Self {
+		Self(s)
+	}
+
+	pub fn new(s: String) -> Result<Self, EmptyError<String>> {
+		if s.len() != 0 {
+			Ok(Self(s))
+		} else {
+			Err(EmptyError(s))
+		}
+	}
+
+	#[track_caller]
+	pub fn expect_new(s: String) -> Self {
+		Self::new(s).expect("expected a non-empty String")
+	}
+
+	pub fn from_utf8(vec: IdealVec<u8>) -> Result<Self, FromUtf8Error> {
+		String::from_utf8(vec.0).map(|s| Self(s))
+	}
+
+	pub unsafe fn from_utf8_unchecked(vec: IdealVec<u8>) -> Self {
+		Self(String::from_utf8_unchecked(vec.0))
+	}
+
+	pub fn get(self) -> String {
+		self.0
+	}
+
+	pub fn get_ref<'a>(&'a self) -> &'a String {
+		&self.0
+	}
+
+	pub unsafe fn get_mut<'a>(&'a mut self) -> &'a mut String {
+		&mut self.0
+	}
+
+	pub fn as_ideal_str(&self) -> &IdealStr {
+		unsafe {IdealStr::new_ref_unchecked(self.0.as_str())}
+	}
+
+	pub fn as_mut_ideal_str(&mut self) -> &mut IdealStr {
+		unsafe {IdealStr::new_mut_unchecked(self.0.as_mut_str())}
+	}
+
+	pub fn len(&self) -> NonZeroUsize {
+		unsafe {NonZeroUsize::new_unchecked(self.0.len())}
+	}
+
+	pub fn into_bytes(self) -> IdealVec<u8> {
+		IdealVec(self.0.into_bytes())
+	}
+
+	pub fn into_boxed_ideal_str(self) -> Box<IdealStr> {
+		unsafe {IdealStr::new_ptr_unchecked::<WithBox>(self.0.into_boxed_str())}
+	}
+
+	#[inline]
+	pub fn push(&mut self, ch: char) {
+		self.0.push(ch)
+	}
+
+	#[inline]
+	pub fn push_str(&mut self, string: &str) {
+		self.0.push_str(string)
+	}
+
+	/// Removes the last character, unless it is the only one left.
+	pub fn pop_if_many(&mut self) -> Option<char> {
+		let last = self.last_char();
+		if self.0.len() > last.len_utf8() {
+			self.0.pop()
+		} else {
+			None
+		}
+	}
+}
+
+impl AsMut<str> for IdealString {
+	fn as_mut(&mut self) -> &mut str {self.0.as_mut_str()}
+}
+
+impl AsMut<IdealStr> for IdealString {
+	fn as_mut(&mut self) -> &mut IdealStr {self.as_mut_ideal_str()}
+}
+
+impl AsRef<[u8]> for IdealString {
+	fn as_ref(&self) -> &[u8] {self.0.as_bytes()}
+}
+
+impl AsRef<str> for IdealString {
+	fn as_ref(&self) -> &str {self.0.as_str()}
+}
+
+impl AsRef<IdealStr> for IdealString {
+	fn as_ref(&self) -> &IdealStr {self.as_ideal_str()}
+}
+
+impl AsRef<Self> for IdealString {
+	fn as_ref(&self) -> &Self {self}
+}
+
+impl Borrow<str> for IdealString {
+	fn borrow(&self) -> &str {self.0.as_str()}
+}
+
+impl Borrow<IdealStr> for IdealString {
+	fn borrow(&self) -> &IdealStr {self.as_ideal_str()}
+}
+
+impl BorrowMut<str> for IdealString {
+	fn borrow_mut(&mut self) -> &mut str {self.0.as_mut_str()}
+}
+
+impl BorrowMut<IdealStr> for IdealString {
+	fn borrow_mut(&mut self) -> &mut IdealStr {self.as_mut_ideal_str()}
+}
+
+impl Clone for IdealString {
+	fn clone(&self) -> Self {
+		Self(self.0.clone())
+	}
+
+	fn clone_from(&mut self, other: &Self) {
+		self.0.clone_from(&other.0)
+	}
+}
+
+impl Debug for IdealString {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {Debug::fmt(&self.0, f)}
+}
+
+impl Deref for IdealString {
+	type Target = IdealStr;
+	fn deref(&self) -> &Self::Target {self.as_ideal_str()}
+}
+
+impl DerefMut for IdealString {
+	fn deref_mut(&mut self) -> &mut Self::Target {self.as_mut_ideal_str()}
+}
+
+impl Display for IdealString {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {Display::fmt(&self.0, f)}
+}
+
+impl Eq for IdealString {}
+
+impl<'a> Extend<&'a str> for IdealString {
+	#[inline]
+	fn extend<I: IntoIterator<Item = &'a str>>(&mut self, iter: I) {self.0.extend(iter)}
+}
+
+impl Extend<char> for IdealString {
+	#[inline]
+	fn extend<I: IntoIterator<Item = char>>(&mut self, iter: I) {self.0.extend(iter)}
+	#[inline]
+	fn extend_one(&mut self, c: char) {self.0.extend_one(c)}
+	#[inline]
+	fn extend_reserve(&mut self, additional: usize) {<String as Extend<char>>::extend_reserve(&mut self.0, additional)}
+}
+
+impl From<char> for IdealString {
+	fn from(c: char) -> Self {
+		let mut s = String::with_capacity(c.len_utf8());
+		s.push(c);
+		Self(s)
+	}
+}
+
+impl From<&IdealStr> for IdealString {
+	fn from(s: &IdealStr) -> Self {s.to_ideal_string()}
+}
+
+impl From<Box<IdealStr>> for IdealString {
+	fn from(s: Box<IdealStr>) -> Self {s.into_ideal_string()}
+}
+
+impl<'a> From<Cow<'a, IdealStr>> for IdealString {
+	fn from(s: Cow<'a, IdealStr>) -> Self {s.into_owned()}
+}
+
+impl From<IdealString> for String {
+	fn from(s: IdealString) -> Self {s.0}
+}
+
+impl Hash for IdealString {
+	#[inline]
+	fn hash<H: hash::Hasher>(&self, state: &mut H) {self.0.hash(state)}
+}
+
+impl Ord for IdealString {
+	#[inline]
+	fn cmp(&self, other: &Self) -> Ordering {self.0.cmp(&other.0)}
+}
+
+impl PartialEq<str> for IdealString {
+	#[inline]
+	fn eq(&self, other: &str) -> bool {*self.0 == *other}
+}
+
+impl<'a> PartialEq<&'a str> for IdealString {
+	#[inline]
+	fn eq(&self, other: &&'a str) -> bool {*self.0 == **other}
+}
+
+impl<'a> PartialEq<&'a IdealStr> for IdealString {
+	#[inline]
+	fn eq(&self, other: &&'a IdealStr) -> bool {*self.0 == other.0}
+}
+
+impl PartialEq<String> for IdealString {
+	#[inline]
+	fn eq(&self, other: &String) -> bool {self.0 == *other}
+}
+
+impl PartialEq<Self> for IdealString {
+	#[inline]
+	fn eq(&self, other: &Self) -> bool {self.0 == other.0}
+}
+
+impl PartialOrd<String> for IdealString {
+	#[inline]
+	fn partial_cmp(&self, other: &String) -> Option<Ordering> {self.0.partial_cmp(other)}
+}
+
+impl PartialOrd<Self> for IdealString {
+	#[inline]
+	fn partial_cmp(&self, other: &Self) -> Option<Ordering> {self.0.partial_cmp(&other.0)}
+}
+
+impl TryFrom<String> for IdealString {
+	type Error = EmptyError<String>;
+	fn try_from(s: String) -> Result<Self, Self::Error> {
+		Self::new(s)
+	}
+}
+
+impl TryFrom<IdealVec<u8>> for IdealString {
+	type Error = FromUtf8Error;
+	fn try_from(vec: IdealVec<u8>) -> Result<Self, Self::Error> {
+		Self::from_utf8(vec)
+	}
+}
+
+impl Write for IdealString {
+	#[inline]
+	fn write_str(&mut self, s: &str) -> fmt::Result {self.0.write_str(s)}
+	#[inline]
+	fn write_char(&mut self, c: char) -> fmt::Result {self.0.write_char(c)}
+}
\ No newline at end of file
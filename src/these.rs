@@ -0,0 +1,97 @@
+/// One value, the other, or both; the result of splitting something that cannot come out empty on both sides.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum These<L, R> {
+	Left(L),
+	Right(R),
+	Both(L, R),
+}
+
+impl<L, R> These<L, R> {
+	#[inline]
+	pub fn is_left(&self) -> bool {
+		matches!(self, These::Left(_))
+	}
+
+	#[inline]
+	pub fn is_right(&self) -> bool {
+		matches!(self, These::Right(_))
+	}
+
+	#[inline]
+	pub fn is_both(&self) -> bool {
+		matches!(self, These::Both(_, _))
+	}
+
+	#[inline]
+	pub fn as_ref(&self) -> These<&L, &R> {
+		match self {
+			These::Left(left) => These::Left(left),
+			These::Right(right) => These::Right(right),
+			These::Both(left, right) => These::Both(left, right),
+		}
+	}
+
+	#[inline]
+	pub fn left(self) -> Option<L> {
+		self.into_options().0
+	}
+
+	#[inline]
+	pub fn right(self) -> Option<R> {
+		self.into_options().1
+	}
+
+	#[inline]
+	pub fn into_options(self) -> (Option<L>, Option<R>) {
+		match self {
+			These::Left(left) => (Some(left), None),
+			These::Right(right) => (None, Some(right)),
+			These::Both(left, right) => (Some(left), Some(right)),
+		}
+	}
+
+	/// Returns `None` only if both sides are `None`.
+	#[inline]
+	pub fn from_options(left: Option<L>, right: Option<R>) -> Option<Self> {
+		match (left, right) {
+			(Some(left), None) => Some(These::Left(left)),
+			(None, Some(right)) => Some(These::Right(right)),
+			(Some(left), Some(right)) => Some(These::Both(left, right)),
+			(None, None) => None,
+		}
+	}
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Either<L, R> {
+	Left(L),
+	Right(R),
+}
+
+impl<L, R> Either<L, R> {
+	#[inline]
+	pub fn is_left(&self) -> bool {
+		matches!(self, Either::Left(_))
+	}
+
+	#[inline]
+	pub fn is_right(&self) -> bool {
+		matches!(self, Either::Right(_))
+	}
+
+	#[inline]
+	pub fn left(self) -> Option<L> {
+		match self {
+			Either::Left(left) => Some(left),
+			Either::Right(_) => None,
+		}
+	}
+
+	#[inline]
+	pub fn right(self) -> Option<R> {
+		match self {
+			Either::Left(_) => None,
+			Either::Right(right) => Some(right),
+		}
+	}
+}
\ No newline at end of file
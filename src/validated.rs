@@ -0,0 +1,157 @@
+use core::iter::FromIterator;
+use alloc::{
+	vec,
+	vec::Vec,
+};
+use super::vec::*;
+
+/// A result that accumulates every error instead of stopping at the first.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Validated<T, E> {
+	Valid(T),
+	Invalid(IdealVec<E>),
+}
+
+pub use Validated::{
+	Invalid,
+	Valid,
+};
+
+impl<T, E> Validated<T, E> {
+	#[inline]
+	pub fn invalid(error: E) -> Self {
+		Invalid(IdealVec(vec![error]))
+	}
+
+	#[inline]
+	pub fn is_valid(&self) -> bool {
+		matches!(self, Valid(_))
+	}
+
+	#[inline]
+	pub fn is_invalid(&self) -> bool {
+		matches!(self, Invalid(_))
+	}
+
+	#[inline]
+	pub fn as_ref(&self) -> Validated<&T, &E> {
+		match self {
+			Valid(value) => Valid(value),
+			Invalid(errors) => Invalid(IdealVec(errors.0.iter().collect())),
+		}
+	}
+
+	#[inline]
+	pub fn map<U, F: FnOnce(T) -> U>(self, f: F) -> Validated<U, E> {
+		match self {
+			Valid(value) => Valid(f(value)),
+			Invalid(errors) => Invalid(errors),
+		}
+	}
+
+	/// Maps each accumulated error.
+	pub fn map_err<G, F: FnMut(E) -> G>(self, f: F) -> Validated<T, G> {
+		match self {
+			Valid(value) => Valid(value),
+			Invalid(errors) => Invalid(IdealVec(errors.0.into_iter().map(f).collect())),
+		}
+	}
+
+	/// Chains a validation that depends on the value; unlike `zip`, this cannot accumulate errors from both sides.
+	#[inline]
+	pub fn and_then<U, F: FnOnce(T) -> Validated<U, E>>(self, f: F) -> Validated<U, E> {
+		match self {
+			Valid(value) => f(value),
+			Invalid(errors) => Invalid(errors),
+		}
+	}
+
+	/// Keeps the value of `other` if both are valid, otherwise the errors of both.
+	#[inline]
+	pub fn and<U>(self, other: Validated<U, E>) -> Validated<U, E> {
+		self.zip_with(other, |_, value| value)
+	}
+
+	#[inline]
+	pub fn zip<U>(self, other: Validated<U, E>) -> Validated<(T, U), E> {
+		self.zip_with(other, |a, b| (a, b))
+	}
+
+	pub fn zip_with<U, R, F: FnOnce(T, U) -> R>(self, other: Validated<U, E>, f: F) -> Validated<R, E> {
+		match (self, other) {
+			(Valid(a), Valid(b)) => Valid(f(a, b)),
+			(Valid(_), Invalid(errors)) | (Invalid(errors), Valid(_)) => Invalid(errors),
+			(Invalid(mut errors), Invalid(mut more)) => {
+				errors.append(&mut more.0);
+				Invalid(errors)
+			},
+		}
+	}
+
+	#[inline]
+	pub fn into_result(self) -> Result<T, IdealVec<E>> {
+		match self {
+			Valid(value) => Ok(value),
+			Invalid(errors) => Err(errors),
+		}
+	}
+
+	#[inline]
+	pub fn ok(self) -> Option<T> {
+		self.into_result().ok()
+	}
+
+	/// Collects every value, or every error if there was at least one.
+	#[inline]
+	pub fn collect_results<I: IntoIterator<Item = Result<T, E>>>(iter: I) -> Result<Vec<T>, IdealVec<E>> {
+		iter.into_iter().collect::<Validated<Vec<T>, E>>().into_result()
+	}
+}
+
+impl<T, E> From<Result<T, E>> for Validated<T, E> {
+	#[inline]
+	fn from(result: Result<T, E>) -> Self {
+		match result {
+			Ok(value) => Valid(value),
+			Err(error) => Self::invalid(error),
+		}
+	}
+}
+
+impl<T, E> From<Result<T, IdealVec<E>>> for Validated<T, E> {
+	#[inline]
+	fn from(result: Result<T, IdealVec<E>>) -> Self {
+		match result {
+			Ok(value) => Valid(value),
+			Err(errors) => Invalid(errors),
+		}
+	}
+}
+
+impl<T, E> From<Validated<T, E>> for Result<T, IdealVec<E>> {
+	#[inline]
+	fn from(validated: Validated<T, E>) -> Self {validated.into_result()}
+}
+
+impl<T, E, C: FromIterator<T>> FromIterator<Validated<T, E>> for Validated<C, E> {
+	fn from_iter<I: IntoIterator<Item = Validated<T, E>>>(iter: I) -> Self {
+		let mut errors = Vec::new();
+		let values = C::from_iter(iter.into_iter().filter_map(|item| match item {
+			Valid(value) => Some(value),
+			Invalid(mut more) => {
+				errors.append(&mut more.0);
+				None
+			},
+		}));
+		match IdealVec::new(errors) {
+			Ok(errors) => Invalid(errors),
+			Err(_) => Valid(values),
+		}
+	}
+}
+
+impl<T, E, C: FromIterator<T>> FromIterator<Result<T, E>> for Validated<C, E> {
+	fn from_iter<I: IntoIterator<Item = Result<T, E>>>(iter: I) -> Self {
+		iter.into_iter().map(Validated::from).collect()
+	}
+}
\ No newline at end of file
@@ -4,6 +4,7 @@ use core::{
 		BorrowMut
 	},
 	cmp::Ordering,
+	convert::TryFrom,
 	fmt::{
 		self,
 		Debug,
@@ -23,7 +24,6 @@ use core::{
 	slice::{
 		Iter,
 		IterMut,
-		SliceIndex
 	},
 };
 use alloc::{
@@ -38,10 +38,25 @@ use alloc::{
 	},
 };
 
+#[cfg(any(feature = "std", feature = "hashbrown"))]
 use super::{
+	hash_map::*,
+	hash_set::*,
+};
+use super::{
+	binary_heap::*,
+	btree_map::*,
+	btree_set::*,
+	error::*,
+	iter::*,
 	pointer_class::*,
 	slice::*,
+	str::*,
+	string::*,
+	these::*,
+	validated::*,
 	array::*,
+	vec_deque::*,
 };
 
 #[repr(transparent)]
@@ -52,18 +67,47 @@ impl<T> IdealVec<T> {
 		Self(vec)
 	}
 
-	pub fn new(vec: Vec<T>) -> Option<Self> {
+	pub fn new(vec: Vec<T>) -> Result<Self, EmptyError<Vec<T>>> {
 		if vec.len() != 0 {
-			Some(Self(vec))
+			Ok(Self(vec))
 		} else {
-			None
+			Err(EmptyError(vec))
 		}
 	}
 
+	#[track_caller]
+	pub fn expect_new(vec: Vec<T>) -> Self {
+		Self::new(vec).expect("expected a non-empty Vec")
+	}
+
+	pub fn singleton(value: T) -> Self {
+		Self(alloc::vec![value])
+	}
+
+	pub fn from_head_tail(head: T, mut tail: Vec<T>) -> Self {
+		tail.insert(0, head);
+		Self(tail)
+	}
+
+	pub fn from_elem(elem: T, n: NonZeroUsize) -> Self where T: Clone {
+		Self(alloc::vec![elem; n.get()])
+	}
+
+	/// Collects `iter`, handing back the empty `Vec` if it yields nothing.
+	pub fn try_from_iter<I: IntoIterator<Item = T>>(iter: I) -> Result<Self, EmptyError<Vec<T>>> {
+		Self::new(Vec::from_iter(iter))
+	}
+
 	pub fn get(self) -> Vec<T> {
 		self.0
 	}
 
+	pub fn into_head_tail(self) -> (T, Vec<T>) {
+		let mut tail = self.0;
+		let head = tail.remove(0);
+		(head, tail)
+	}
+
 	pub fn get_ref<'a>(&'a self) -> &'a Vec<T> {
 		&self.0
 	}
@@ -83,6 +127,203 @@ impl<T> IdealVec<T> {
 	pub fn into_boxed_ideal_slice(self) -> Box<IdealSlice<T>> {
 		unsafe {IdealSlice::new_ptr_unchecked::<WithBox>(self.0.into_boxed_slice())}
 	}
+
+	#[inline]
+	pub fn into_ideal_iter(self) -> IdealIter<IntoIter<T>> {
+		IdealIter(self.0.into_iter())
+	}
+
+	/// Groups runs of consecutive elements that share a key, in order.
+	pub fn into_groups_by_key<K: PartialEq, F: FnMut(&T) -> K>(self, mut f: F) -> IdealVec<(K, IdealVec<T>)> {
+		let mut iter = self.0.into_iter();
+		let first = unsafe {unwrap_unchecked(iter.next())};
+		let mut groups = Vec::new();
+		let mut key = f(&first);
+		let mut group = alloc::vec![first];
+		for value in iter {
+			let next = f(&value);
+			if next == key {
+				group.push(value);
+			} else {
+				groups.push((core::mem::replace(&mut key, next), Self(core::mem::replace(&mut group, alloc::vec![value]))));
+			}
+		}
+		groups.push((key, Self(group)));
+		IdealVec(groups)
+	}
+
+	/// Reuses the allocation when `U` has the same size and alignment as `T`.
+	pub fn map<U, F: FnMut(T) -> U>(self, f: F) -> IdealVec<U> {
+		IdealVec(self.0.into_iter().map(f).collect())
+	}
+
+	/// Stops at the first error; like `map`, reuses the allocation where it can.
+	pub fn try_map<U, E, F: FnMut(T) -> Result<U, E>>(self, f: F) -> Result<IdealVec<U>, E> {
+		self.0.into_iter().map(f).collect::<Result<_, _>>().map(IdealVec)
+	}
+
+	/// Splits into the elements for which `predicate` returns `true` and those for which it returns `false`.
+	pub fn partition_ideal<F: FnMut(&T) -> bool>(self, predicate: F) -> These<Self, Self> {
+		let (left, right): (Vec<T>, Vec<T>) = self.0.into_iter().partition(predicate);
+		unsafe {unwrap_unchecked(These::from_options(Self::new(left).ok(), Self::new(right).ok()))}
+	}
+
+	pub fn partition_map<L, R, F: FnMut(T) -> Either<L, R>>(self, mut f: F) -> These<IdealVec<L>, IdealVec<R>> {
+		let mut left = Vec::new();
+		let mut right = Vec::new();
+		for value in self.0 {
+			match f(value) {
+				Either::Left(value) => left.push(value),
+				Either::Right(value) => right.push(value),
+			}
+		}
+		unsafe {unwrap_unchecked(These::from_options(IdealVec::new(left).ok(), IdealVec::new(right).ok()))}
+	}
+
+	#[inline]
+	pub fn push(&mut self, value: T) {
+		self.0.push(value)
+	}
+
+	#[inline]
+	pub fn insert(&mut self, index: usize, element: T) {
+		self.0.insert(index, element)
+	}
+
+	#[inline]
+	pub fn append(&mut self, other: &mut Vec<T>) {
+		self.0.append(other)
+	}
+
+	/// Removes the last element, unless it is the only one left.
+	pub fn pop_if_many(&mut self) -> Option<T> {
+		if self.0.len() > 1 {
+			self.0.pop()
+		} else {
+			None
+		}
+	}
+
+	/// Removes the element at `index`, unless it is the only one left.
+	///
+	/// Panics if `index` is out of bounds.
+	pub fn remove_if_many(&mut self, index: usize) -> Option<T> {
+		if self.0.len() > 1 {
+			Some(self.0.remove(index))
+		} else {
+			assert!(index < self.0.len(), "removal index (is {}) should be < len (is {})", index, self.0.len());
+			None
+		}
+	}
+
+	/// Swap-removes the element at `index`, unless it is the only one left.
+	///
+	/// Panics if `index` is out of bounds.
+	pub fn swap_remove_if_many(&mut self, index: usize) -> Option<T> {
+		if self.0.len() > 1 {
+			Some(self.0.swap_remove(index))
+		} else {
+			assert!(index < self.0.len(), "swap_remove index (is {}) should be < len (is {})", index, self.0.len());
+			None
+		}
+	}
+
+	#[inline]
+	pub fn truncate(&mut self, len: NonZeroUsize) {
+		self.0.truncate(len.get())
+	}
+
+	/// Removes every element but the first.
+	#[inline]
+	pub fn clear_tail(&mut self) {
+		self.0.truncate(1)
+	}
+
+	/// Runs `f` on the inner `Vec`, handing back the emptied `Vec` if `f` leaves it empty.
+	pub fn with_vec<R>(mut self, f: impl FnOnce(&mut Vec<T>) -> R) -> Result<(Self, R), (Vec<T>, R)> {
+		let result = f(&mut self.0);
+		if self.0.len() != 0 {
+			Ok((self, result))
+		} else {
+			Err((self.0, result))
+		}
+	}
+
+	/// Lends a working copy of the inner `Vec` through a guard, which writes it back when finished or dropped unless it was left empty.
+	///
+	/// Forgetting the guard discards the changes. The contents are cloned up front; use `with_vec` to avoid the clone.
+	pub fn vec_mut<'a>(&'a mut self) -> VecMutGuard<'a, T> where T: Clone {
+		VecMutGuard {
+			working: self.0.clone(),
+			vec: &mut self.0,
+		}
+	}
+
+	/// Runs `f` on the inner `Vec` through `vec_mut`, returning `Err` if the changes were discarded for leaving it empty.
+	pub fn with_vec_mut<R>(&mut self, f: impl FnOnce(&mut Vec<T>) -> R) -> Result<R, R> where T: Clone {
+		let mut guard = self.vec_mut();
+		let result = f(&mut guard);
+		if guard.finish() {
+			Ok(result)
+		} else {
+			Err(result)
+		}
+	}
+}
+
+impl<T> IdealVec<Option<T>> {
+	pub fn transpose(self) -> Option<IdealVec<T>> {
+		self.0.into_iter().collect::<Option<_>>().map(IdealVec)
+	}
+}
+
+impl<T, E> IdealVec<Result<T, E>> {
+	/// Stops at the first error.
+	pub fn transpose(self) -> Result<IdealVec<T>, E> {
+		self.try_map(|result| result)
+	}
+
+	/// Like `transpose`, but collects every error.
+	pub fn transpose_all(self) -> Result<IdealVec<T>, IdealVec<E>> {
+		Validated::collect_results(self.0).map(IdealVec)
+	}
+}
+
+/// The inner `Vec` is only ever replaced by a non-empty working copy, so leaking the guard cannot empty it.
+pub struct VecMutGuard<'a, T> {
+	vec: &'a mut Vec<T>,
+	working: Vec<T>,
+}
+
+impl<'a, T> VecMutGuard<'a, T> {
+	/// Ends the scope, returning `false` if the changes were discarded for leaving the copy empty.
+	pub fn finish(mut self) -> bool {
+		self.write_back()
+	}
+
+	fn write_back(&mut self) -> bool {
+		if self.working.len() != 0 {
+			*self.vec = core::mem::take(&mut self.working);
+			true
+		} else {
+			false
+		}
+	}
+}
+
+impl<'a, T> Deref for VecMutGuard<'a, T> {
+	type Target = Vec<T>;
+	fn deref(&self) -> &Vec<T> {&self.working}
+}
+
+impl<'a, T> DerefMut for VecMutGuard<'a, T> {
+	fn deref_mut(&mut self) -> &mut Vec<T> {&mut self.working}
+}
+
+impl<'a, T> Drop for VecMutGuard<'a, T> {
+	fn drop(&mut self) {
+		self.write_back();
+	}
 }
 
 impl<T> AsMut<[T]> for IdealVec<T> {
@@ -186,7 +427,39 @@ impl<T> Extend<T> for IdealVec<T> {
 	fn extend_reserve(&mut self, additional: usize) {self.0.extend_reserve(additional)}
 }
 
-// TODO: Implement From RefIdealStr, IdealBinaryHeap, IdealString, IdealVecDeque
+impl<T> From<(T, Vec<T>)> for IdealVec<T> {
+	fn from((head, tail): (T, Vec<T>)) -> Self {Self::from_head_tail(head, tail)}
+}
+
+impl<T> From<IdealBinaryHeap<T>> for IdealVec<T> {
+	fn from(h: IdealBinaryHeap<T>) -> IdealVec<T> {h.into_ideal_vec()}
+}
+
+impl From<&IdealStr> for IdealVec<u8> {
+	fn from(s: &IdealStr) -> IdealVec<u8> {Self(Vec::from(&s.0))}
+}
+
+impl From<IdealString> for IdealVec<u8> {
+	fn from(s: IdealString) -> IdealVec<u8> {s.into_bytes()}
+}
+
+impl<K, V> From<IdealBTreeMap<K, V>> for IdealVec<(K, V)> {
+	fn from(m: IdealBTreeMap<K, V>) -> IdealVec<(K, V)> {m.into_ideal_vec()}
+}
+
+impl<T> From<IdealBTreeSet<T>> for IdealVec<T> {
+	fn from(s: IdealBTreeSet<T>) -> IdealVec<T> {s.into_sorted_ideal_vec()}
+}
+
+#[cfg(any(feature = "std", feature = "hashbrown"))]
+impl<K, V, S> From<IdealHashMap<K, V, S>> for IdealVec<(K, V)> {
+	fn from(m: IdealHashMap<K, V, S>) -> IdealVec<(K, V)> {m.into_ideal_vec()}
+}
+
+#[cfg(any(feature = "std", feature = "hashbrown"))]
+impl<T, S> From<IdealHashSet<T, S>> for IdealVec<T> {
+	fn from(s: IdealHashSet<T, S>) -> IdealVec<T> {s.into_ideal_vec()}
+}
 
 impl<T: Clone> From<&IdealSlice<T>> for IdealVec<T> {
 	fn from(s: &IdealSlice<T>) -> IdealVec<T> {Self(Vec::from(&s.0))}
@@ -215,9 +488,8 @@ where
     }
 }
 
-impl<T> FromIterator<T> for IdealVec<T> {
-	#[inline]
-	fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> IdealVec<T> {Self(Vec::<T>::from_iter(iter))}
+impl<T> From<IdealVecDeque<T>> for IdealVec<T> {
+	fn from(d: IdealVecDeque<T>) -> IdealVec<T> {Self(Vec::from(d.0))}
 }
 
 impl<T: Hash> Hash for IdealVec<T> {
@@ -225,15 +497,15 @@ impl<T: Hash> Hash for IdealVec<T> {
 	fn hash<H: hash::Hasher>(&self, state: &mut H) {self.0.hash(state)}
 }
 
-impl<T, I: SliceIndex<[T]>> Index<I> for IdealVec<T> {
-	type Output = I::Output;
+impl<T, I> Index<I> for IdealVec<T> where IdealSlice<T>: Index<I> {
+	type Output = <IdealSlice<T> as Index<I>>::Output;
 	#[inline]
-	fn index(&self, index: I) -> &Self::Output {self.0.index(index)}
+	fn index(&self, index: I) -> &Self::Output {self.as_ideal_slice().index(index)}
 }
 
-impl<T, I: SliceIndex<[T]>> IndexMut<I> for IdealVec<T> {
+impl<T, I> IndexMut<I> for IdealVec<T> where IdealSlice<T>: IndexMut<I> {
 	#[inline]
-	fn index_mut(&mut self, index: I) -> &mut Self::Output {self.0.index_mut(index)}
+	fn index_mut(&mut self, index: I) -> &mut Self::Output {(**self).index_mut(index)}
 }
 
 impl<T> IntoIterator for IdealVec<T> {
@@ -263,6 +535,12 @@ impl<'a, T> IntoIterator for &'a mut IdealVec<T> {
 	}
 }
 
+unsafe impl<T> NonEmptyIterator for IdealVec<T> {}
+
+unsafe impl<'a, T> NonEmptyIterator for &'a IdealVec<T> {}
+
+unsafe impl<'a, T> NonEmptyIterator for &'a mut IdealVec<T> {}
+
 impl<T: Ord> Ord for IdealVec<T> {
 	#[inline]
 	fn cmp(&self, other: &Self) -> Ordering {self.0.cmp(&other.0)}
@@ -332,4 +610,11 @@ impl<T: PartialOrd<T>> PartialOrd<Vec<T>> for IdealVec<T> {
 impl<T: PartialOrd<T>> PartialOrd<Self> for IdealVec<T> {
     #[inline]
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {self.0.partial_cmp(&other.0)}
+}
+
+impl<T> TryFrom<Vec<T>> for IdealVec<T> {
+	type Error = EmptyError<Vec<T>>;
+	fn try_from(vec: Vec<T>) -> Result<Self, Self::Error> {
+		Self::new(vec)
+	}
 }
\ No newline at end of file
@@ -0,0 +1,238 @@
+use core::{
+	cmp::Ordering,
+	fmt::{
+		self,
+		Debug,
+	},
+	hash::{
+		self,
+		Hash,
+	},
+	num::NonZeroUsize,
+	ops::{
+		Index,
+		IndexMut,
+	},
+};
+use alloc::collections::vec_deque::{
+	IntoIter,
+	Iter,
+	IterMut,
+	VecDeque,
+};
+use super::{
+	error::*,
+	iter::*,
+	slice::*,
+	vec::*,
+};
+
+#[repr(transparent)]
+pub struct IdealVecDeque<T>(pub(crate) VecDeque<T>);
+
+impl<T> IdealVecDeque<T> {
+	pub unsafe fn new_unchecked(deque: VecDeque<T>) -> Self {
+		Self(deque)
+	}
+
+	pub fn new(deque: VecDeque<T>) -> Result<Self, EmptyError<VecDeque<T>>> {
+		if deque.len() != 0 {
+			Ok(Self(deque))
+		} else {
+			Err(EmptyError(deque))
+		}
+	}
+
+	#[track_caller]
+	pub fn expect_new(deque: VecDeque<T>) -> Self {
+		Self::new(deque).expect("expected a non-empty VecDeque")
+	}
+
+	pub fn get(self) -> VecDeque<T> {
+		self.0
+	}
+
+	pub fn get_ref<'a>(&'a self) -> &'a VecDeque<T> {
+		&self.0
+	}
+
+	pub unsafe fn get_mut<'a>(&'a mut self) -> &'a mut VecDeque<T> {
+		&mut self.0
+	}
+
+	pub fn len(&self) -> NonZeroUsize {
+		unsafe {NonZeroUsize::new_unchecked(self.0.len())}
+	}
+
+	#[inline]
+	pub fn front(&self) -> &T {
+		unsafe {unwrap_unchecked(self.0.front())}
+	}
+
+	#[inline]
+	pub fn front_mut(&mut self) -> &mut T {
+		unsafe {unwrap_unchecked(self.0.front_mut())}
+	}
+
+	#[inline]
+	pub fn back(&self) -> &T {
+		unsafe {unwrap_unchecked(self.0.back())}
+	}
+
+	#[inline]
+	pub fn back_mut(&mut self) -> &mut T {
+		unsafe {unwrap_unchecked(self.0.back_mut())}
+	}
+
+	#[inline]
+	pub fn push_front(&mut self, value: T) {
+		self.0.push_front(value)
+	}
+
+	#[inline]
+	pub fn push_back(&mut self, value: T) {
+		self.0.push_back(value)
+	}
+
+	/// Removes the first element, unless it is the only one left.
+	pub fn pop_front_if_many(&mut self) -> Option<T> {
+		if self.0.len() > 1 {
+			self.0.pop_front()
+		} else {
+			None
+		}
+	}
+
+	/// Removes the last element, unless it is the only one left.
+	pub fn pop_back_if_many(&mut self) -> Option<T> {
+		if self.0.len() > 1 {
+			self.0.pop_back()
+		} else {
+			None
+		}
+	}
+
+	#[inline]
+	pub fn truncate(&mut self, len: NonZeroUsize) {
+		self.0.truncate(len.get())
+	}
+
+	#[inline]
+	pub fn rotate_left(&mut self, mid: usize) {
+		self.0.rotate_left(mid)
+	}
+
+	#[inline]
+	pub fn rotate_right(&mut self, k: usize) {
+		self.0.rotate_right(k)
+	}
+
+	#[inline]
+	pub fn make_contiguous(&mut self) -> &mut IdealSlice<T> {
+		unsafe {IdealSlice::new_mut_unchecked(self.0.make_contiguous())}
+	}
+
+	#[inline]
+	pub fn iter<'a>(&'a self) -> IdealIter<Iter<'a, T>> {
+		IdealIter(self.0.iter())
+	}
+
+	#[inline]
+	pub fn iter_mut<'a>(&'a mut self) -> IdealIter<IterMut<'a, T>> {
+		IdealIter(self.0.iter_mut())
+	}
+
+	#[inline]
+	pub fn into_ideal_iter(self) -> IdealIter<IntoIter<T>> {
+		IdealIter(self.0.into_iter())
+	}
+}
+
+impl<T: Clone> Clone for IdealVecDeque<T> {
+	fn clone(&self) -> Self {
+		Self(self.0.clone())
+	}
+}
+
+impl<T: Debug> Debug for IdealVecDeque<T> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {self.0.fmt(f)}
+}
+
+impl<T: Eq> Eq for IdealVecDeque<T> {}
+
+impl<'a, T: 'a + Copy> Extend<&'a T> for IdealVecDeque<T> {
+	#[inline]
+	fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {self.0.extend(iter)}
+}
+
+impl<T> Extend<T> for IdealVecDeque<T> {
+	#[inline]
+	fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {self.0.extend(iter)}
+}
+
+impl<T> From<IdealVec<T>> for IdealVecDeque<T> {
+	fn from(v: IdealVec<T>) -> Self {Self(VecDeque::from(v.0))}
+}
+
+impl<T: Hash> Hash for IdealVecDeque<T> {
+	#[inline]
+	fn hash<H: hash::Hasher>(&self, state: &mut H) {self.0.hash(state)}
+}
+
+impl<T> Index<usize> for IdealVecDeque<T> {
+	type Output = T;
+	#[inline]
+	fn index(&self, index: usize) -> &T {self.0.index(index)}
+}
+
+impl<T> IndexMut<usize> for IdealVecDeque<T> {
+	#[inline]
+	fn index_mut(&mut self, index: usize) -> &mut T {self.0.index_mut(index)}
+}
+
+impl<T> IntoIterator for IdealVecDeque<T> {
+	type Item = T;
+	type IntoIter = IntoIter<T>;
+	#[inline]
+	fn into_iter(self) -> Self::IntoIter {
+		self.0.into_iter()
+	}
+}
+
+impl<'a, T> IntoIterator for &'a IdealVecDeque<T> {
+	type Item = &'a T;
+	type IntoIter = Iter<'a, T>;
+	#[inline]
+	fn into_iter(self) -> Self::IntoIter {
+		self.0.iter()
+	}
+}
+
+impl<'a, T> IntoIterator for &'a mut IdealVecDeque<T> {
+	type Item = &'a mut T;
+	type IntoIter = IterMut<'a, T>;
+	#[inline]
+	fn into_iter(self) -> Self::IntoIter {
+		self.0.iter_mut()
+	}
+}
+
+impl<T: Ord> Ord for IdealVecDeque<T> {
+	#[inline]
+	fn cmp(&self, other: &Self) -> Ordering {self.0.cmp(&other.0)}
+}
+
+impl<A: PartialEq> PartialEq<VecDeque<A>> for IdealVecDeque<A> {
+	#[inline]
+	fn eq(&self, other: &VecDeque<A>) -> bool {self.0 == *other}
+}
+
+impl<A: PartialEq> PartialEq<Self> for IdealVecDeque<A> {
+	#[inline]
+	fn eq(&self, other: &Self) -> bool {self.0 == other.0}
+}
+
+impl<T: PartialOrd> PartialOrd<Self> for IdealVecDeque<T> {
+	#[inline]
+	fn partial_cmp(&self, other: &Self) -> Option<Ordering> {self.0.partial_cmp(&other.0)}
+}
\ No newline at end of file
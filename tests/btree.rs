@@ -0,0 +1,21 @@
+use std::collections::{BTreeMap, BTreeSet};
+use idealist::{btree_map::IdealBTreeMap, btree_set::IdealBTreeSet, error::LastEntryError};
+
+#[test]
+fn btree_map_remove_if_many_tells_absent_from_refused() {
+	let mut map = IdealBTreeMap::expect_new((0..2).map(|k| (k, k)).collect::<BTreeMap<_, _>>());
+	assert_eq!(map.remove_if_many(&0), Ok(Some(0)));
+	assert_eq!(map.remove_if_many(&0), Ok(None));
+	assert_eq!(map.remove_entry_if_many(&1), Err(LastEntryError));
+	assert_eq!(map.remove_if_many(&7), Ok(None));
+	assert_eq!(map.get_value(&1), Some(&1));
+}
+
+#[test]
+fn btree_set_remove_if_many_tells_absent_from_refused() {
+	let mut set = IdealBTreeSet::new((0..2).collect::<BTreeSet<_>>()).unwrap();
+	assert_eq!(set.remove_if_many(&0), Ok(true));
+	assert_eq!(set.remove_if_many(&0), Ok(false));
+	assert_eq!(set.remove_if_many(&1), Err(LastEntryError));
+	assert!(set.contains(&1));
+}
\ No newline at end of file
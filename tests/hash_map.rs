@@ -0,0 +1,53 @@
+#![cfg(all(feature = "std", not(feature = "hashbrown")))]
+
+use std::collections::{HashMap, HashSet};
+use idealist::{error::LastEntryError, hash_map::IdealHashMap, hash_set::IdealHashSet};
+
+#[test]
+fn retain_calls_predicate_once_per_entry() {
+	let mut map = IdealHashMap::expect_new((0..8).map(|k| (k, k)).collect::<HashMap<_, _>>());
+	let mut calls = 0;
+	assert!(map.retain(|_, _| {
+		calls += 1;
+		calls == 1
+	}));
+	assert_eq!(calls, 8);
+	assert_eq!(map.len().get(), 1);
+
+	let mut set = IdealHashSet::expect_new((0..8).collect::<HashSet<_>>());
+	let mut calls = 0;
+	assert!(set.retain(|_| {
+		calls += 1;
+		calls == 8
+	}));
+	assert_eq!(calls, 8);
+	assert_eq!(set.len().get(), 1);
+}
+
+#[test]
+fn retain_rejecting_everything_leaves_contents_unchanged() {
+	let entries = (0..8).map(|k| (k, k)).collect::<HashMap<_, _>>();
+	let mut map = IdealHashMap::expect_new(entries.clone());
+	assert!(!map.retain(|_, _| false));
+	assert_eq!(map, entries);
+
+	let values = (0..8).collect::<HashSet<_>>();
+	let mut set = IdealHashSet::expect_new(values.clone());
+	assert!(!set.retain(|_| false));
+	assert_eq!(set, values);
+}
+
+#[test]
+fn remove_if_many_tells_absent_from_refused() {
+	let mut map = IdealHashMap::expect_new((0..2).map(|k| (k, k)).collect::<HashMap<_, _>>());
+	assert_eq!(map.remove_if_many(&0), Ok(Some(0)));
+	assert_eq!(map.remove_if_many(&0), Ok(None));
+	assert_eq!(map.remove_entry_if_many(&1), Err(LastEntryError));
+	assert_eq!(map[&1], 1);
+
+	let mut set = IdealHashSet::expect_new((0..2).collect::<HashSet<_>>());
+	assert_eq!(set.remove_if_many(&0), Ok(true));
+	assert_eq!(set.remove_if_many(&0), Ok(false));
+	assert_eq!(set.remove_if_many(&1), Err(LastEntryError));
+	assert!(set.contains(&1));
+}
\ No newline at end of file
@@ -0,0 +1,67 @@
+use idealist::{ideal_vec, iter::NonEmptyIterator};
+
+#[test]
+fn forgotten_vec_mut_guard_leaves_vec_untouched() {
+	let mut v = ideal_vec![1, 2, 3];
+	let mut guard = v.vec_mut();
+	guard.clear();
+	core::mem::forget(guard);
+	assert_eq!(v, [1, 2, 3]);
+	assert_eq!(v.len().get(), 3);
+}
+
+#[test]
+fn vec_mut_guard_writes_back_unless_empty() {
+	let mut v = ideal_vec![1, 2, 3];
+	let mut guard = v.vec_mut();
+	guard.push(4);
+	assert!(guard.finish());
+	assert_eq!(v, [1, 2, 3, 4]);
+	{
+		let mut guard = v.vec_mut();
+		guard.retain(|_| false);
+	}
+	assert_eq!(v, [1, 2, 3, 4]);
+	assert_eq!(v.with_vec_mut(|vec| vec.truncate(1)), Ok(()));
+	assert_eq!(v, [1]);
+	assert_eq!(v.with_vec_mut(|vec| vec.clear()), Err(()));
+	assert_eq!(v, [1]);
+}
+
+#[test]
+fn map_in_place_replaces_every_element() {
+	let mut v = ideal_vec![1, 2, 3];
+	v.map_in_place(|x| x * 10);
+	assert_eq!(v, [10, 20, 30]);
+	v[..=1].map_in_place(|x| x + 1);
+	assert_eq!(v, [11, 21, 30]);
+}
+
+#[test]
+fn array_try_map_drops_written_elements_on_error() {
+	use std::rc::Rc;
+	let counter = Rc::new(());
+	let array = idealist::ideal_array![0, 1, 2, 3];
+	let result = array.try_map(|x| if x < 2 { Ok(Rc::clone(&counter)) } else { Err(x) });
+	assert_eq!(result.err(), Some(2));
+	assert_eq!(Rc::strong_count(&counter), 1);
+}
+
+#[test]
+fn array_transpose_all_collects_every_error() {
+	let array = idealist::ideal_array![Ok(1), Err('a'), Ok(2), Err('b')];
+	assert_eq!(array.transpose_all().err().unwrap(), ['a', 'b']);
+	let array = idealist::ideal_array![Ok::<_, ()>(String::from("x")), Ok(String::from("y"))];
+	assert_eq!(array.transpose_all().unwrap().get(), [String::from("x"), String::from("y")]);
+}
+
+#[test]
+fn containers_iterate_as_non_empty() {
+	let mut v = ideal_vec![3, 1, 2];
+	// `Ord` is implemented for references too, so its `max` and `min` need disambiguating.
+	assert_eq!(*NonEmptyIterator::max(&v), 3);
+	assert_eq!(*(&v[..]).min_by_key(|x| *x), 1);
+	assert_eq!(*(&idealist::ideal_array![4, 6, 5]).max_by(|a, b| a.cmp(b)), 6);
+	*(&mut v).first() = 4;
+	assert_eq!(v.collect_ideal(), [4, 1, 2]);
+}
\ No newline at end of file